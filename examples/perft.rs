@@ -0,0 +1,96 @@
+//! Times perft on a position to benchmark move generation.
+//!
+//! Usage: `cargo run --release --example perft [depth] [fen]`. Without a FEN,
+//! the start position and Kiwipete are both timed, along with sliding attack
+//! lookups through the magic tables against scanning each ray.
+
+use std::env;
+use std::hint::black_box;
+use std::time::Instant;
+use website::chess::bitboard::Bitboard;
+use website::chess::board::Board;
+use website::chess::magic;
+use website::chess::utils::{Dir, Square};
+
+const POSITIONS: [(&str, &str, u32); 2] = [
+    (
+        "startpos",
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        6,
+    ),
+    (
+        "kiwipete",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        5,
+    ),
+];
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let depth = args
+        .first()
+        .map(|d| d.parse().expect("depth must be a number"));
+
+    if args.len() > 1 {
+        time_perft("fen", &args[1..].join(" "), depth.unwrap_or(5));
+    } else {
+        for (name, fen, default_depth) in POSITIONS {
+            time_perft(name, fen, depth.unwrap_or(default_depth));
+        }
+        time_attacks();
+    }
+}
+
+fn time_perft(name: &str, fen: &str, depth: u32) {
+    let mut board = Board::from_fen(fen).expect("invalid FEN");
+    let start = Instant::now();
+    let nodes = board.perft(depth);
+    let elapsed = start.elapsed();
+    println!(
+        "{name} depth {depth}: {nodes} nodes in {:.3}s ({:.1} Mnps)",
+        elapsed.as_secs_f64(),
+        nodes as f64 / elapsed.as_secs_f64() / 1e6
+    );
+}
+
+fn time_attacks() {
+    use Dir::*;
+    const LOOKUPS: u64 = 10_000_000;
+    // Pseudo-random occupancies from a xorshift generator
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let occupancies: Vec<Bitboard> = (0..1024)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            Bitboard(state & (state >> 3))
+        })
+        .collect();
+    let squares: Vec<Square> = Bitboard(!0).collect();
+
+    let time = |name: &str, attacks: &dyn Fn(Square, Bitboard) -> Bitboard| {
+        let start = Instant::now();
+        for i in 0..LOOKUPS as usize {
+            let s = squares[i % 64];
+            let occupied_bb = occupancies[i % occupancies.len()];
+            black_box(attacks(black_box(s), black_box(occupied_bb)));
+        }
+        let elapsed = start.elapsed();
+        println!(
+            "{name}: {LOOKUPS} lookups in {:.3}s ({:.1} ns each)",
+            elapsed.as_secs_f64(),
+            elapsed.as_nanos() as f64 / LOOKUPS as f64
+        );
+    };
+
+    time("magic rook + bishop", &|s, occupied_bb| {
+        magic::rook_attacks(s, occupied_bb) | magic::bishop_attacks(s, occupied_bb)
+    });
+    time("ray scan rook + bishop", &|s, occupied_bb| {
+        [Nort, Noea, East, Soea, Sout, Sowe, West, Nowe]
+            .iter()
+            .fold(Bitboard(0), |a, &d| {
+                a | magic::ray_attacks(d, s, occupied_bb)
+            })
+    });
+}
@@ -0,0 +1,227 @@
+use axum::{
+    extract::Query,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::task;
+use website::chess::board::{Board, Status};
+use website::chess::cmove::CMove;
+use website::chess::engine::{self, SearchLimits};
+use website::chess::tt::TranspositionTable;
+
+/// How long the engine thinks for when a request doesn't say, in ms
+const DEFAULT_MOVETIME_MS: u64 = 1000;
+/// The longest a request can have the engine think for, in ms
+const MAX_MOVETIME_MS: u64 = 10_000;
+/// The deepest a request can have the engine search
+const MAX_DEPTH: u32 = 32;
+/// The size of the transposition table each search gets, in MB. Kept small
+/// since every search in flight holds its own.
+pub const SEARCH_HASH_MB: usize = 1;
+
+/// An error answered with its status code and `{"error": "..."}`
+pub struct ApiError(StatusCode, String);
+
+impl ApiError {
+    pub fn new(status: StatusCode, err: impl ToString) -> ApiError {
+        ApiError(status, err.to_string())
+    }
+
+    pub fn bad_request(err: impl ToString) -> ApiError {
+        ApiError::new(StatusCode::BAD_REQUEST, err)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = Json(serde_json::json!({ "error": self.1 }));
+        (self.0, body).into_response()
+    }
+}
+
+/// A position, given as a FEN string and the moves played from it in UCI
+/// notation separated by spaces, like the UCI `position` command. The moves
+/// let repetitions be spotted. The FEN is the starting position if left out.
+#[derive(Deserialize)]
+pub struct PositionQuery {
+    fen: Option<String>,
+    moves: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct MoveRequest {
+    fen: Option<String>,
+    moves: Option<String>,
+    /// The move in UCI notation, e.g. `e2e4`, or SAN, e.g. `e4`
+    #[serde(rename = "move")]
+    m: String,
+}
+
+#[derive(Deserialize)]
+pub struct BestMoveQuery {
+    fen: Option<String>,
+    moves: Option<String>,
+    depth: Option<u32>,
+    movetime: Option<u64>,
+}
+
+#[derive(Serialize)]
+pub struct MoveJson {
+    uci: String,
+    san: String,
+}
+
+#[derive(Serialize)]
+pub struct MovesResponse {
+    fen: String,
+    status: &'static str,
+    moves: Vec<MoveJson>,
+}
+
+#[derive(Serialize)]
+pub struct MoveResponse {
+    #[serde(rename = "move")]
+    m: MoveJson,
+    fen: String,
+    status: &'static str,
+}
+
+/// The engine's reply. `mate` is the number of moves to mate, negative if
+/// the side to move is getting mated, and `score` is in centipawns otherwise.
+#[derive(Serialize)]
+pub struct BestMoveResponse {
+    #[serde(rename = "move")]
+    m: Option<MoveJson>,
+    score: Option<i32>,
+    mate: Option<i32>,
+    depth: u32,
+    nodes: u64,
+    pv: Vec<String>,
+}
+
+pub fn routes_api() -> Router {
+    Router::new()
+        .route("/api/chess/moves", get(moves_handler))
+        .route("/api/chess/move", post(move_handler))
+        .route("/api/chess/bestmove", get(bestmove_handler))
+}
+
+/// Lists the legal moves in a position
+pub async fn moves_handler(
+    Query(query): Query<PositionQuery>,
+) -> Result<Json<MovesResponse>, ApiError> {
+    let board = parse_board(query.fen.as_deref(), query.moves.as_deref())?;
+    let moves = board
+        .generate_moves()
+        .into_iter()
+        .map(|m| move_json(&board, m))
+        .collect();
+    Ok(Json(MovesResponse {
+        fen: board.to_fen(),
+        status: status_name(board.status()),
+        moves,
+    }))
+}
+
+/// Plays a move, answering with the position after it
+pub async fn move_handler(
+    Json(request): Json<MoveRequest>,
+) -> Result<Json<MoveResponse>, ApiError> {
+    let mut board = parse_board(request.fen.as_deref(), request.moves.as_deref())?;
+    let m = board
+        .parse_uci_move(&request.m)
+        .or_else(|_| board.parse_san(&request.m))
+        .map_err(ApiError::bad_request)?;
+    let m_json = move_json(&board, m);
+    board.make_move_mut(&m);
+    Ok(Json(MoveResponse {
+        m: m_json,
+        fen: board.to_fen(),
+        status: status_name(board.status()),
+    }))
+}
+
+/// Searches a position for `movetime` ms, or to `depth` if it gets there
+/// first. The search runs on the blocking thread pool so that it doesn't
+/// hold up other requests.
+pub async fn bestmove_handler(
+    Query(query): Query<BestMoveQuery>,
+) -> Result<Json<BestMoveResponse>, ApiError> {
+    let mut board = parse_board(query.fen.as_deref(), query.moves.as_deref())?;
+    let limits = search_limits(query.depth, query.movetime);
+
+    let response = task::spawn_blocking(move || {
+        let tt = TranspositionTable::new(SEARCH_HASH_MB);
+        let result = engine::search_with_table(&mut board, &limits, &tt, 1, &mut |_| {});
+        let (score, mate) = score_json(result.score);
+        BestMoveResponse {
+            m: result.best_move.map(|m| move_json(&board, m)),
+            score,
+            mate,
+            depth: result.depth,
+            nodes: result.nodes,
+            pv: result.pv.iter().map(|m| m.to_uci()).collect(),
+        }
+    })
+    .await
+    .map_err(|err| ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, err))?;
+    Ok(Json(response))
+}
+
+fn parse_board(fen: Option<&str>, moves: Option<&str>) -> Result<Board, ApiError> {
+    let mut board = match fen {
+        Some(fen) => Board::from_fen(fen).map_err(ApiError::bad_request)?,
+        None => Board::new(),
+    };
+    for uci_move in moves.unwrap_or_default().split_whitespace() {
+        let m = board
+            .parse_uci_move(uci_move)
+            .map_err(ApiError::bad_request)?;
+        board.make_move_mut(&m);
+    }
+    Ok(board)
+}
+
+/// Returns the limits for a search to `depth` plies for `movetime` ms, both
+/// capped so that no one request can tie up the server
+pub fn search_limits(depth: Option<u32>, movetime: Option<u64>) -> SearchLimits {
+    let movetime = movetime
+        .unwrap_or(DEFAULT_MOVETIME_MS)
+        .clamp(1, MAX_MOVETIME_MS);
+    SearchLimits {
+        depth: depth.map(|depth| depth.clamp(1, MAX_DEPTH)),
+        movetime: Some(Duration::from_millis(movetime)),
+        ..Default::default()
+    }
+}
+
+/// Splits a search score into a centipawn score or a number of moves to
+/// mate, negative if the side to move is getting mated
+pub fn score_json(score: i32) -> (Option<i32>, Option<i32>) {
+    match engine::mate_in(score) {
+        Some(moves) => (None, Some(moves)),
+        None => (Some(score), None),
+    }
+}
+
+pub fn move_json(board: &Board, m: CMove) -> MoveJson {
+    MoveJson {
+        uci: m.to_uci(),
+        san: board.to_san(m),
+    }
+}
+
+pub fn status_name(status: Status) -> &'static str {
+    match status {
+        Status::Ongoing => "ongoing",
+        Status::Checkmate => "checkmate",
+        Status::Stalemate => "stalemate",
+        Status::DrawFiftyMove => "draw_fifty_move",
+        Status::DrawRepetition => "draw_repetition",
+        Status::DrawInsufficientMaterial => "draw_insufficient_material",
+    }
+}
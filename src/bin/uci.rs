@@ -0,0 +1,232 @@
+//! Speaks the Universal Chess Interface over stdin and stdout, so that the
+//! engine can be played against other engines with cutechess-cli or loaded
+//! into a chess GUI.
+//!
+//! Usage: `cargo run --release --bin uci`
+//!
+//! The protocol is specified at:
+//! <https://www.chessprogramming.org/UCI>
+
+use std::io::{self, BufRead};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use website::chess::board::Board;
+use website::chess::engine::{self, SearchLimits, SearchResult};
+use website::chess::tt::{self, TranspositionTable};
+
+/// The largest transposition table that can be set with the `Hash` option, in MB
+const MAX_HASH_MB: usize = 4096;
+/// The most search threads that can be set with the `Threads` option
+const MAX_THREADS: usize = 256;
+/// Every keyword `go` can be given, which ends a `searchmoves` list
+const GO_KEYWORDS: [&str; 12] = [
+    "searchmoves",
+    "ponder",
+    "wtime",
+    "btime",
+    "winc",
+    "binc",
+    "movestogo",
+    "depth",
+    "nodes",
+    "mate",
+    "movetime",
+    "infinite",
+];
+
+struct Uci {
+    board: Board,
+    tt: Arc<TranspositionTable>,
+    threads: usize,
+    /// The stop flag and thread of the search in progress, if any
+    search: Option<(Arc<AtomicBool>, JoinHandle<()>)>,
+}
+
+fn main() {
+    let mut uci = Uci {
+        board: Board::new(),
+        tt: Arc::new(TranspositionTable::default()),
+        threads: 1,
+        search: None,
+    };
+
+    for line in io::stdin().lock().lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        match tokens.first().copied() {
+            Some("uci") => {
+                println!("id name website");
+                println!("id author Yonah Goldberg");
+                println!(
+                    "option name Hash type spin default {} min 1 max {MAX_HASH_MB}",
+                    tt::DEFAULT_SIZE_MB
+                );
+                println!("option name Threads type spin default 1 min 1 max {MAX_THREADS}");
+                println!("uciok");
+            }
+            Some("isready") => println!("readyok"),
+            Some("ucinewgame") => {
+                uci.stop();
+                uci.board = Board::new();
+                uci.tt.clear();
+            }
+            Some("position") => {
+                uci.stop();
+                uci.position(&tokens[1..]);
+            }
+            Some("go") => {
+                uci.stop();
+                uci.go(&tokens[1..]);
+            }
+            // Pondering ends with playing the best move found so far
+            Some("stop") | Some("ponderhit") => uci.stop(),
+            Some("setoption") => {
+                uci.stop();
+                uci.set_option(&tokens[1..]);
+            }
+            Some("quit") => break,
+            _ => {}
+        }
+    }
+    uci.stop();
+}
+
+impl Uci {
+    /// Stops the search in progress, if any, waiting for it to report its
+    /// best move
+    fn stop(&mut self) {
+        if let Some((stop, handle)) = self.search.take() {
+            stop.store(true, Ordering::Relaxed);
+            handle.join().unwrap();
+        }
+    }
+
+    /// Handles `position [startpos | fen <fen>] [moves <move>...]`
+    fn position(&mut self, args: &[&str]) {
+        let moves_at = args.iter().position(|&t| t == "moves");
+        let (setup, moves) = args.split_at(moves_at.unwrap_or(args.len()));
+        let board = match setup {
+            ["startpos"] => Ok(Board::new()),
+            ["fen", fen @ ..] => Board::from_fen(&fen.join(" ")),
+            _ => {
+                println!("info string expected startpos or fen");
+                return;
+            }
+        };
+        self.board = match board {
+            Ok(board) => board,
+            Err(err) => {
+                println!("info string {err}");
+                return;
+            }
+        };
+
+        for &uci_move in moves.iter().skip(1) {
+            match self.board.parse_uci_move(uci_move) {
+                Ok(m) => self.board.make_move_mut(&m),
+                Err(err) => {
+                    println!("info string {err}");
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Handles `go` with `depth`, `nodes`, `movetime`, `wtime`, `btime`,
+    /// `winc`, `binc`, `movestogo`, `infinite` and `ponder`, starting a search
+    /// on another thread so that `stop` can still be read. `searchmoves` is
+    /// read but ignored, since every move is always searched.
+    fn go(&mut self, args: &[&str]) {
+        let mut limits = SearchLimits::default();
+        let mut infinite = false;
+
+        let mut args = args.iter().peekable();
+        while let Some(&arg) = args.next() {
+            match arg {
+                "infinite" | "ponder" => {
+                    infinite = true;
+                    continue;
+                }
+                "searchmoves" => {
+                    while args.next_if(|a| !GO_KEYWORDS.contains(a)).is_some() {}
+                    continue;
+                }
+                _ => {}
+            }
+            // A keyword without a value is skipped, leaving the next keyword
+            let Some(value) = args.peek().and_then(|v| v.parse::<u64>().ok()) else {
+                continue;
+            };
+            args.next();
+            let ms = Some(Duration::from_millis(value));
+            match arg {
+                "depth" => limits.depth = Some(value as u32),
+                "nodes" => limits.nodes = Some(value),
+                "movetime" => limits.movetime = ms,
+                "wtime" => limits.wtime = ms,
+                "btime" => limits.btime = ms,
+                "winc" => limits.winc = ms,
+                "binc" => limits.binc = ms,
+                "movestogo" => limits.movestogo = Some(value as u32),
+                _ => {}
+            }
+        }
+
+        let stop = limits.stop.clone();
+        let mut board = self.board.clone();
+        let tt = self.tt.clone();
+        let threads = self.threads;
+        let handle = thread::spawn(move || {
+            let start = Instant::now();
+            let result = engine::search_with_table(&mut board, &limits, &tt, threads, &mut |r| {
+                print_info(r, start.elapsed())
+            });
+            // An infinite or ponder search only reports its move once told to
+            // stop
+            while infinite && !limits.stop.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            match result.best_move {
+                Some(m) => println!("bestmove {}", m.to_uci()),
+                None => println!("bestmove 0000"),
+            }
+        });
+        self.search = Some((stop, handle));
+    }
+
+    /// Handles `setoption name <name> value <value>`
+    fn set_option(&mut self, args: &[&str]) {
+        match args {
+            ["name", "Hash", "value", mb] => match mb.parse::<usize>() {
+                Ok(mb) => self.tt = Arc::new(TranspositionTable::new(mb.clamp(1, MAX_HASH_MB))),
+                Err(_) => println!("info string invalid Hash value {mb}"),
+            },
+            ["name", "Threads", "value", threads] => match threads.parse::<usize>() {
+                Ok(threads) => self.threads = threads.clamp(1, MAX_THREADS),
+                Err(_) => println!("info string invalid Threads value {threads}"),
+            },
+            _ => println!("info string unknown option {}", args.join(" ")),
+        }
+    }
+}
+
+/// Prints the result of a completed iteration of the search
+fn print_info(result: &SearchResult, elapsed: Duration) {
+    let score = match engine::mate_in(result.score) {
+        Some(moves) => format!("mate {moves}"),
+        None => format!("cp {}", result.score),
+    };
+    let ms = elapsed.as_millis().max(1);
+    let pv: Vec<String> = result.pv.iter().map(|m| m.to_uci()).collect();
+    println!(
+        "info depth {} score {score} nodes {} nps {} time {ms} pv {}",
+        result.depth,
+        result.nodes,
+        result.nodes as u128 * 1000 / ms,
+        pv.join(" ")
+    );
+}
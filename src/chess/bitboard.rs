@@ -10,6 +10,7 @@
 /// <https://www.chessprogramming.org/Square_Mapping_Considerations>
 use super::utils::Square;
 use num::FromPrimitive;
+use std::fmt;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl,
     ShlAssign, Shr, ShrAssign, Sub, SubAssign,
@@ -35,9 +36,17 @@ pub const NOT_A_FILE: Bitboard = Bitboard(0xfe_fe_fe_fe_fe_fe_fe_fe);
 /// 1s everywhere except for the H file
 pub const NOT_H_FILE: Bitboard = Bitboard(0x7f_7f_7f_7f_7f_7f_7f_7f);
 
+/// File masks
+pub const A_FILE: Bitboard = Bitboard(0x01_01_01_01_01_01_01_01);
+
 /// Rank masks
+pub const RANK1: Bitboard = Bitboard(0x00_00_00_00_00_00_00_ff);
 pub const RANK4: Bitboard = Bitboard(0x00_00_00_00_ff_00_00_00);
 pub const RANK5: Bitboard = Bitboard(0x00_00_00_ff_00_00_00_00);
+pub const RANK8: Bitboard = Bitboard(0xff_00_00_00_00_00_00_00);
+
+/// 1s on the light squares, e.g. h1
+pub const LIGHT_SQUARES: Bitboard = Bitboard(0x55_aa_55_aa_55_aa_55_aa);
 
 impl Bitboard {
     /// Shifts the bitboard `b` south one
@@ -111,6 +120,11 @@ impl Bitboard {
         }
     }
 
+    /// Returns the number of 1 bits
+    pub fn pop_count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
     pub fn empty(&self) -> bool {
         self.0 == 0
     }
@@ -120,22 +134,21 @@ impl Bitboard {
     }
 }
 
-impl ToString for Bitboard {
-    fn to_string(&self) -> String {
-        let mut res = String::from("");
+impl fmt::Display for Bitboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for i in (0..8).rev() {
             let rank = (self.0 >> (i * 8)) & 0xff;
             for j in 0..8 {
                 let square = (rank >> j) & 1;
                 if square == 1 {
-                    res += "1 ";
+                    write!(f, "1 ")?;
                 } else {
-                    res += ". ";
+                    write!(f, ". ")?;
                 }
             }
-            res += "\n";
+            writeln!(f)?;
         }
-        res
+        Ok(())
     }
 }
 
@@ -257,6 +270,6 @@ impl Add for Bitboard {
 
 impl AddAssign for Bitboard {
     fn add_assign(&mut self, rhs: Self) {
-        *self = Bitboard(self.0 - rhs.0);
+        *self = Bitboard(self.0 + rhs.0);
     }
 }
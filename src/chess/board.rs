@@ -1,33 +1,183 @@
 /// The main `Board` struct, which contains 11 bitboards, a fifty move rule
-/// counter, and castling rights
+/// counter, castling rights, the side to move and the game ply
 ///
 /// # Fields
 ///
 /// * `piece_bb` - contains bitboards marking the presense of
-/// pawns, knights, bishops, rooks, queens, and kings respectively,
-/// regardless of color. The 7th and 8th boards in the array mark the presense
-/// of white and black pieces respectively, which can be intersected with
-/// the previous indexed boards to obtain the location of only white or only
-/// black pieces.
+///   pawns, knights, bishops, rooks, queens, and kings respectively,
+///   regardless of color. The 7th and 8th boards in the array mark the presense
+///   of white and black pieces respectively, which can be intersected with
+///   the previous indexed boards to obtain the location of only white or only
+///   black pieces.
 /// * `empty_bb` - marks the absense of pieces
 /// * `occupied_bb` - makrs the presence of pieces
 /// * `en_passant_bb` - marks the pawns that can be captured by en passant
-/// (they just double pushed)
-/// * `fifty_move_rule_counter` - number of plies so far. After 100 plies with no
-/// pawn move or capture, the game is an automatic draw
+///   (they just double pushed)
+/// * `fifty_move_rule_counter` - number of plies since the last pawn move or
+///   capture. After 100 such plies, the game is an automatic draw
 /// * `castling_rights` - starting from LSB, marks whether castling is possible on
-/// white king-side, white queen-side, black king-side, black queen-side
+///   white king-side, white queen-side, black king-side, black queen-side
+/// * `side_to_move` - the color whose turn it is
+/// * `ply` - number of plies played since the start of the game, from which
+///   the fullmove number is worked out
+/// * `hash` - Zobrist hash of the position, updated incrementally as moves
+///   are made and unmade
+/// * `undo_stack` - one entry per move made, used to unmake moves
 use super::bitboard::{self, Bitboard};
 use super::cmove::{self, CMove};
+use super::magic;
 use super::tables;
 use super::utils::{CPiece, Color, Dir, Piece, Square};
+use super::zobrist;
 use num_traits::FromPrimitive;
+use std::fmt;
 use Color::*;
 use Dir::*;
 use Piece::*;
 
-struct CreateBoardError;
+/// The reasons a `Board` can fail to be created
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CreateBoardError {
+    /// A piece list didn't have exactly 64 entries
+    PieceListLength(usize),
+    /// A FEN string didn't have 4 or 6 space separated fields
+    FenFieldCount(usize),
+    /// The FEN piece placement didn't have exactly 8 ranks
+    RankCount(usize),
+    /// A FEN rank didn't describe exactly 8 files. `rank` counts from 1
+    RankLength { rank: u8, files: usize },
+    /// A FEN rank has two digits in a row, e.g. `44`. `rank` counts from 1
+    ConsecutiveDigits { rank: u8 },
+    /// A character in the FEN piece placement isn't a piece or a digit
+    /// from 1 to 8
+    InvalidPiece(char),
+    /// The FEN side to move isn't `w` or `b`
+    InvalidSideToMove(String),
+    /// The FEN castling rights aren't `-` or a subset of `KQkq`
+    InvalidCastlingRights(String),
+    /// The FEN en passant target isn't `-` or a square on the 3rd or 6th
+    /// rank matching the side to move
+    InvalidEnPassant(String),
+    /// The FEN halfmove clock isn't a number from 0 to 255
+    InvalidHalfmoveClock(String),
+    /// The FEN fullmove number isn't a positive number
+    InvalidFullmoveNumber(String),
+    /// The pieces and state describe a position that can't come up in a game
+    InvalidPosition(PositionError),
+}
+
+impl fmt::Display for CreateBoardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CreateBoardError::*;
+        match self {
+            PieceListLength(len) => write!(f, "expected 64 squares in piece list, got {len}"),
+            FenFieldCount(count) => write!(f, "expected 4 or 6 FEN fields, got {count}"),
+            RankCount(count) => write!(f, "expected 8 ranks in FEN piece placement, got {count}"),
+            RankLength { rank, files } => {
+                write!(f, "expected 8 files on rank {rank}, got {files}")
+            }
+            ConsecutiveDigits { rank } => write!(f, "two digits in a row on rank {rank}"),
+            InvalidPiece(c) => write!(f, "invalid piece '{c}' in FEN piece placement"),
+            InvalidSideToMove(s) => write!(f, "invalid side to move '{s}'"),
+            InvalidCastlingRights(s) => write!(f, "invalid castling rights '{s}'"),
+            InvalidEnPassant(s) => write!(f, "invalid en passant target '{s}'"),
+            InvalidHalfmoveClock(s) => write!(f, "invalid halfmove clock '{s}'"),
+            InvalidFullmoveNumber(s) => write!(f, "invalid fullmove number '{s}'"),
+            InvalidPosition(err) => write!(f, "invalid position: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CreateBoardError {}
+
+impl From<PositionError> for CreateBoardError {
+    fn from(err: PositionError) -> Self {
+        CreateBoardError::InvalidPosition(err)
+    }
+}
 
+/// The reasons a position can't come up in a game, see `Board::validate`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PositionError {
+    /// A side doesn't have exactly one king
+    KingCount { color: Color, count: u32 },
+    /// A side has more than 16 pieces
+    TooManyPieces { color: Color, count: u32 },
+    /// A side has more than 8 pawns
+    TooManyPawns { color: Color, count: u32 },
+    /// A pawn is on the 1st or 8th rank, where it can never be
+    PawnOnBackRank(Square),
+    /// The side that just moved left its king in check
+    OpponentInCheck,
+    /// A side can castle, given as its FEN letter, but its king or the
+    /// rook isn't on its starting square
+    CastlingRights(char),
+    /// En passant is possible, but there is no pawn that could have just
+    /// double pushed to the square given
+    EnPassant(Square),
+}
+
+impl fmt::Display for PositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use PositionError::*;
+        let name = |color: &Color| match color {
+            White => "white",
+            Black => "black",
+        };
+        match self {
+            KingCount { color, count } => write!(f, "{} has {count} kings", name(color)),
+            TooManyPieces { color, count } => write!(f, "{} has {count} pieces", name(color)),
+            TooManyPawns { color, count } => write!(f, "{} has {count} pawns", name(color)),
+            PawnOnBackRank(s) => write!(f, "pawn on back rank square {s}"),
+            OpponentInCheck => write!(f, "the side not to move is in check"),
+            CastlingRights(c) => write!(f, "castling right '{c}' without king and rook"),
+            EnPassant(s) => write!(f, "en passant without a pawn on {s}"),
+        }
+    }
+}
+
+impl std::error::Error for PositionError {}
+
+/// The reasons a move can fail to be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseMoveError {
+    /// The move isn't written in the notation expected
+    InvalidNotation(String),
+    /// The move is written correctly but isn't legal in the position
+    IllegalMove(String),
+    /// The move could be more than one legal move in the position
+    AmbiguousMove(String),
+}
+
+impl fmt::Display for ParseMoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMoveError::InvalidNotation(s) => write!(f, "invalid move notation '{s}'"),
+            ParseMoveError::IllegalMove(s) => write!(f, "illegal move '{s}'"),
+            ParseMoveError::AmbiguousMove(s) => write!(f, "ambiguous move '{s}'"),
+        }
+    }
+}
+
+impl std::error::Error for ParseMoveError {}
+
+/// Whether a game is still being played, and if not, how it ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Ongoing,
+    /// The side to move is in check and has no legal moves, and has lost
+    Checkmate,
+    /// The side to move isn't in check but has no legal moves
+    Stalemate,
+    /// 100 plies have passed without a pawn move or a capture
+    DrawFiftyMove,
+    /// The position has been reached for the third time
+    DrawRepetition,
+    /// Neither side has the material left to checkmate
+    DrawInsufficientMaterial,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Board {
     piece_bb: [Bitboard; 8],
     empty_bb: Bitboard,
@@ -35,18 +185,47 @@ pub struct Board {
     en_passant_bb: Bitboard,
     fifty_move_rule_counter: u8,
     castling_rights: u8,
+    side_to_move: Color,
+    ply: u32,
+    hash: u64,
+    undo_stack: Vec<Undo>,
+}
+
+/// The state `make_move_mut` overwrites that can't be worked out from the
+/// move itself, kept so that `unmake_move` can restore it. The hash of the
+/// position before the move doubles as the game's history for spotting
+/// repetitions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Undo {
+    m: CMove,
+    hash: u64,
+    captured: Option<Piece>,
+    en_passant_bb: Bitboard,
+    fifty_move_rule_counter: u8,
+    castling_rights: u8,
 }
+
 // Constants for masking out castling rights
 const WKING_SIDE_MASK: u8 = 1;
 const WQUEEN_SIDE_MASK: u8 = 2;
 const BKING_SIDE_MASK: u8 = 4;
 const BQUEEN_SIDE_MASK: u8 = 8;
 
+/// Piece values for static exchange evaluation, indexed by `Piece`
+const SEE_VALUES: [i32; 6] = [100, 300, 300, 500, 900, 20_000];
+
+impl Default for Board {
+    fn default() -> Self {
+        Board::new()
+    }
+}
+
 impl Board {
     /// Creates a new Bitboard struct with beginning piece
-    /// placements for each bitboard
+    /// placements for each bitboard, white to move and full
+    /// castling rights
     pub fn new() -> Board {
-        Board {
+        let mut board = Board {
             piece_bb: [
                 bitboard::PAWN_START,
                 bitboard::KNIGHT_START,
@@ -61,20 +240,39 @@ impl Board {
             occupied_bb: bitboard::OCCUPIED_START,
             en_passant_bb: Bitboard(0),
             fifty_move_rule_counter: 0,
-            castling_rights: 0,
-        }
+            castling_rights: WKING_SIDE_MASK
+                | WQUEEN_SIDE_MASK
+                | BKING_SIDE_MASK
+                | BQUEEN_SIDE_MASK,
+            side_to_move: White,
+            ply: 0,
+            hash: 0,
+            undo_stack: Vec::new(),
+        };
+        board.hash = board.compute_hash();
+        board
     }
 
-    pub fn from_piece_list(piece_list: &Vec<Option<CPiece>>) -> Result<Self, CreateBoardError> {
+    /// Creates a board from a list of 64 squares in LERF order, with
+    /// white to move and no castling rights
+    pub fn from_piece_list(piece_list: &[Option<CPiece>]) -> Result<Self, CreateBoardError> {
+        let board = Board::from_piece_list_unchecked(piece_list)?;
+        board.validate()?;
+        Ok(board)
+    }
+
+    /// Creates a board like `from_piece_list` without validating it, for
+    /// constructors that set more of the state first
+    fn from_piece_list_unchecked(piece_list: &[Option<CPiece>]) -> Result<Self, CreateBoardError> {
         if piece_list.len() != 64 {
-            return Err(CreateBoardError);
+            return Err(CreateBoardError::PieceListLength(piece_list.len()));
         }
 
         let mut piece_bb: [Bitboard; 8] = [Bitboard(0); 8];
         let mut occupied_bb = Bitboard(0);
 
-        for i in 0..63 {
-            if let Some(CPiece(piece, color)) = piece_list[i] {
+        for (i, square) in piece_list.iter().enumerate() {
+            if let Some(CPiece(piece, color)) = *square {
                 let square_bb = Bitboard(1 << i);
                 piece_bb[piece as usize] |= square_bb;
                 piece_bb[6 + color as usize] |= square_bb;
@@ -84,32 +282,321 @@ impl Board {
 
         let empty_bb = !occupied_bb;
 
-        Ok(Board {
+        let mut board = Board {
             piece_bb,
             empty_bb,
             occupied_bb,
             en_passant_bb: Bitboard(0),
             fifty_move_rule_counter: 0,
             castling_rights: 0,
-        })
+            side_to_move: White,
+            ply: 0,
+            hash: 0,
+            undo_stack: Vec::new(),
+        };
+        board.hash = board.compute_hash();
+        Ok(board)
     }
 
+    /// Returns the pieces on each of the 64 squares in LERF order
     pub fn to_piece_list(&self) -> Vec<Option<CPiece>> {
-        (0..63)
+        (0..64)
             .map(|num| FromPrimitive::from_i32(num).unwrap())
             .map(|s| self.piece_on_square(s))
             .collect()
     }
 
-    // pub fn from_fen(fen: String) -> Result<Self, CreateBoardError> {
-    //     let parts = fen.split(" ").collect::<Vec>();
-    //     let pieces = parts[0];
-    // }
+    /// Creates a board from a position in Forsyth-Edwards Notation. The
+    /// halfmove clock and fullmove number may be left off, in which case
+    /// they default to 0 and 1.
+    pub fn from_fen(fen: &str) -> Result<Self, CreateBoardError> {
+        let fields: Vec<&str> = fen.split_whitespace().collect();
+        if fields.len() != 4 && fields.len() != 6 {
+            return Err(CreateBoardError::FenFieldCount(fields.len()));
+        }
+
+        let mut board = Board::from_piece_list_unchecked(&Board::parse_fen_placement(fields[0])?)?;
+        board.side_to_move = match fields[1] {
+            "w" => White,
+            "b" => Black,
+            s => return Err(CreateBoardError::InvalidSideToMove(s.to_string())),
+        };
+        board.castling_rights = Board::parse_fen_castling(fields[2])?;
+        board.en_passant_bb = Board::parse_fen_en_passant(fields[3], board.side_to_move)?;
+
+        let mut fullmove_number: u16 = 1;
+        if fields.len() == 6 {
+            board.fifty_move_rule_counter = fields[4]
+                .parse()
+                .map_err(|_| CreateBoardError::InvalidHalfmoveClock(fields[4].to_string()))?;
+            fullmove_number = match fields[5].parse() {
+                Ok(n) if n > 0 => n,
+                _ => {
+                    return Err(CreateBoardError::InvalidFullmoveNumber(
+                        fields[5].to_string(),
+                    ))
+                }
+            };
+        }
+        board.ply = 2 * (fullmove_number as u32 - 1) + board.side_to_move as u32;
+        board.hash = board.compute_hash();
+        board.validate()?;
+
+        Ok(board)
+    }
+
+    /// Checks that the position could come up in a game, so that move
+    /// generation can rely on it: each side has one king and at most 16
+    /// pieces and 8 pawns, no pawn is on a back rank, the side not to move
+    /// isn't in check, and the castling rights and en passant square match
+    /// the pieces. Every constructor calls this.
+    pub fn validate(&self) -> Result<(), PositionError> {
+        use Square::*;
+
+        for color in [White, Black] {
+            let count = self.piece_bb(Some(color), King).pop_count();
+            if count != 1 {
+                return Err(PositionError::KingCount { color, count });
+            }
+            let count = self.color_bb(color).pop_count();
+            if count > 16 {
+                return Err(PositionError::TooManyPieces { color, count });
+            }
+            let count = self.piece_bb(Some(color), Pawn).pop_count();
+            if count > 8 {
+                return Err(PositionError::TooManyPawns { color, count });
+            }
+        }
+
+        let back_ranks = bitboard::RANK1 | bitboard::RANK8;
+        if let Some(s) = (self.piece_bb(None, Pawn) & back_ranks).bit_scan() {
+            return Err(PositionError::PawnOnBackRank(s));
+        }
+
+        let them = !self.side_to_move;
+        let their_king = self.piece_bb(Some(them), King).bit_scan().unwrap();
+        if self
+            .attacks_to(their_king, self.side_to_move, None)
+            .occupied()
+        {
+            return Err(PositionError::OpponentInCheck);
+        }
+
+        for (mask, c, color, king, rook) in [
+            (WKING_SIDE_MASK, 'K', White, E1, H1),
+            (WQUEEN_SIDE_MASK, 'Q', White, E1, A1),
+            (BKING_SIDE_MASK, 'k', Black, E8, H8),
+            (BQUEEN_SIDE_MASK, 'q', Black, E8, A8),
+        ] {
+            if self.castling_rights & mask > 0
+                && (self.piece_on_square(king) != Some(CPiece(King, color))
+                    || self.piece_on_square(rook) != Some(CPiece(Rook, color)))
+            {
+                return Err(PositionError::CastlingRights(c));
+            }
+        }
+
+        // The pawn that just double pushed, with the squares it passed
+        // through empty
+        if let Some(pawn_square) = self.en_passant_bb.bit_scan() {
+            let dir = match them {
+                White => Sout,
+                Black => Nort,
+            };
+            let skipped = pawn_square.translate(dir, 1);
+            let start = pawn_square.translate(dir, 2);
+            let empty = |s: Option<Square>| s.is_some_and(|s| self.piece_on_square(s).is_none());
+            if self.piece_on_square(pawn_square) != Some(CPiece(Pawn, them))
+                || !empty(skipped)
+                || !empty(start)
+            {
+                return Err(PositionError::EnPassant(pawn_square));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the piece list described by the piece placement field of a FEN string
+    fn parse_fen_placement(placement: &str) -> Result<Vec<Option<CPiece>>, CreateBoardError> {
+        let ranks: Vec<&str> = placement.split('/').collect();
+        if ranks.len() != 8 {
+            return Err(CreateBoardError::RankCount(ranks.len()));
+        }
+
+        let mut piece_list = vec![None; 64];
+        // FEN lists the 8th rank first
+        for (rank, pieces) in (0..8).rev().zip(ranks) {
+            let mut file = 0;
+            let mut after_digit = false;
+            for c in pieces.chars() {
+                if let Some(skip) = c.to_digit(10).filter(|d| (1..=8).contains(d)) {
+                    if after_digit {
+                        return Err(CreateBoardError::ConsecutiveDigits {
+                            rank: rank as u8 + 1,
+                        });
+                    }
+                    after_digit = true;
+                    file += skip as usize;
+                } else {
+                    after_digit = false;
+                    let piece =
+                        CPiece::from_fen_char(c).ok_or(CreateBoardError::InvalidPiece(c))?;
+                    if file < 8 {
+                        piece_list[rank * 8 + file] = Some(piece);
+                    }
+                    file += 1;
+                }
+            }
+            if file != 8 {
+                return Err(CreateBoardError::RankLength {
+                    rank: rank as u8 + 1,
+                    files: file,
+                });
+            }
+        }
+
+        Ok(piece_list)
+    }
+
+    /// Returns the castling rights described by the castling field of a FEN string
+    fn parse_fen_castling(castling: &str) -> Result<u8, CreateBoardError> {
+        if castling == "-" {
+            return Ok(0);
+        }
+
+        let mut castling_rights = 0;
+        for c in castling.chars() {
+            let mask = match c {
+                'K' => WKING_SIDE_MASK,
+                'Q' => WQUEEN_SIDE_MASK,
+                'k' => BKING_SIDE_MASK,
+                'q' => BQUEEN_SIDE_MASK,
+                _ => 0,
+            };
+            // Unknown or repeated letters
+            if mask == 0 || castling_rights & mask > 0 {
+                return Err(CreateBoardError::InvalidCastlingRights(
+                    castling.to_string(),
+                ));
+            }
+            castling_rights |= mask;
+        }
+        Ok(castling_rights)
+    }
+
+    /// Returns the en passant bitboard described by the en passant target field
+    /// of a FEN string. FEN marks the square behind the pawn that just double
+    /// pushed, while `en_passant_bb` marks the pawn itself.
+    fn parse_fen_en_passant(
+        target: &str,
+        side_to_move: Color,
+    ) -> Result<Bitboard, CreateBoardError> {
+        if target == "-" {
+            return Ok(Bitboard(0));
+        }
+
+        let err = || CreateBoardError::InvalidEnPassant(target.to_string());
+        let target = Square::from_algebraic(target).ok_or_else(err)?;
+        let pawn_square = match (side_to_move, target.rank()) {
+            (White, 5) => Square::from_file_rank(target.file(), 4),
+            (Black, 2) => Square::from_file_rank(target.file(), 3),
+            _ => None,
+        }
+        .ok_or_else(err)?;
+        Ok(pawn_square.as_bitboard())
+    }
+
+    /// Returns this position in Forsyth-Edwards Notation
+    pub fn to_fen(&self) -> String {
+        let mut fen = String::new();
+
+        for rank in (0..8).rev() {
+            let mut empty = 0;
+            for file in 0..8 {
+                // Both are in range
+                let square = Square::from_file_rank(file, rank).unwrap();
+                if let Some(piece) = self.piece_on_square(square) {
+                    if empty > 0 {
+                        fen.push_str(&empty.to_string());
+                        empty = 0;
+                    }
+                    fen.push(piece.to_fen_char());
+                } else {
+                    empty += 1;
+                }
+            }
+            if empty > 0 {
+                fen.push_str(&empty.to_string());
+            }
+            if rank > 0 {
+                fen.push('/');
+            }
+        }
+
+        fen.push_str(match self.side_to_move {
+            White => " w ",
+            Black => " b ",
+        });
+
+        if self.castling_rights == 0 {
+            fen.push('-');
+        } else {
+            for (mask, c) in [
+                (WKING_SIDE_MASK, 'K'),
+                (WQUEEN_SIDE_MASK, 'Q'),
+                (BKING_SIDE_MASK, 'k'),
+                (BQUEEN_SIDE_MASK, 'q'),
+            ] {
+                if self.castling_rights & mask > 0 {
+                    fen.push(c);
+                }
+            }
+        }
+
+        fen.push(' ');
+        match self.en_passant_bb.bit_scan() {
+            Some(pawn_square) => {
+                // The target square is the one the pawn skipped over
+                let target_rank = if pawn_square.rank() == 3 { 2 } else { 5 };
+                let target = Square::from_file_rank(pawn_square.file(), target_rank).unwrap();
+                fen.push_str(&target.to_string());
+            }
+            None => fen.push('-'),
+        }
+
+        fen.push_str(&format!(
+            " {} {}",
+            self.fifty_move_rule_counter,
+            self.fullmove_number()
+        ));
+        fen
+    }
+
+    /// Returns the color whose turn it is
+    pub fn side_to_move(&self) -> Color {
+        self.side_to_move
+    }
+
+    /// Returns the number of plies played since the start of the game
+    pub fn ply(&self) -> u32 {
+        self.ply
+    }
+
+    /// Returns the number of plies since the last pawn move or capture
+    pub fn fifty_move_rule_counter(&self) -> u8 {
+        self.fifty_move_rule_counter
+    }
+
+    /// Returns the fullmove number, which starts at 1 and is incremented
+    /// after each black move
+    pub fn fullmove_number(&self) -> u32 {
+        self.ply / 2 + 1
+    }
 
     /// Returns the appropriate piece bitboard for
     /// piece `p` intersected with the piece bitboard
     /// for the color `c`, if `c` is not `None`
-    fn piece_bb(&self, c: Option<Color>, p: Piece) -> Bitboard {
+    pub fn piece_bb(&self, c: Option<Color>, p: Piece) -> Bitboard {
         let intersection = match c {
             Some(c) => self.piece_bb[6 + c as usize],
             None => Bitboard(!0),
@@ -118,17 +605,19 @@ impl Board {
     }
 
     /// Returns a bitboard of all the pieces of color `c`
-    fn color_bb(&self, c: Color) -> Bitboard {
+    pub fn color_bb(&self, c: Color) -> Bitboard {
         self.piece_bb[6 + c as usize] & self.occupied_bb
     }
 
     /// Returns a bitboard marking the squares pawns of color `c` can be
     /// single pushed to under pseudo-legal move generation
+    #[allow(dead_code)]
     fn pawn_push_targets(&self, c: Color) -> Bitboard {
         ((self.piece_bb(Some(c), Pawn) << 8) >> ((c as i32) << 4)) & self.empty_bb
     }
     /// Returns a bitboard marking the squares pawns of color `c` can be
     /// double pushed to under pseudo-legal move generation
+    #[allow(dead_code)]
     fn pawn_dpush_targets(&self, c: Color) -> Bitboard {
         let push_targets = self.pawn_push_targets(c);
         match c {
@@ -160,7 +649,7 @@ impl Board {
             Black => {
                 let empty_rank6 =
                     Bitboard::nort_one(self.empty_bb & bitboard::RANK5) & self.empty_bb;
-                Bitboard::sout_one(empty_rank6) & piece_bb
+                Bitboard::nort_one(empty_rank6) & piece_bb
             }
         }
     }
@@ -187,18 +676,20 @@ impl Board {
 
     /// Returns a bitboard marking the squares pawns of color `c` can attack
     /// under pseudo-legal move generation
-    fn pawn_attack_squares(&self, c: Color) -> Bitboard {
+    pub(crate) fn pawn_attack_squares(&self, c: Color) -> Bitboard {
         self.pawn_west_attack_squares(c) | self.pawn_east_attack_squares(c)
     }
 
     /// Returns a bitboard marking the squares in which 2 pawns of color `c` can attack
     /// under pseudo-legal move generation
+    #[allow(dead_code)]
     fn pawn_dbl_attack_squares(&self, c: Color) -> Bitboard {
         self.pawn_west_attack_squares(c) & self.pawn_east_attack_squares(c)
     }
 
     /// Returns a bitboard marking the squares in which a single pawn of color `c` attacks
     /// under pseudo-legal move generation
+    #[allow(dead_code)]
     fn pawn_single_attack_squares(&self, c: Color) -> Bitboard {
         self.pawn_west_attack_squares(c) ^ self.pawn_east_attack_squares(c)
     }
@@ -206,6 +697,7 @@ impl Board {
     /// Returns a bitboard marking safe pawn squares. A safe pawn square
     /// for the player playing color `c` are the squares in which they have
     /// more pawns attacking than their oponent
+    #[allow(dead_code)]
     fn pawn_safe_sqares(&self, c: Color) -> Bitboard {
         self.pawn_dbl_attack_squares(c)
             | !self.pawn_attack_squares(!c)
@@ -214,6 +706,7 @@ impl Board {
 
     /// Returns a bitboard marking the pawns of color `c` that
     /// can capture another pawn to the east under pseudo-legal move generation
+    #[allow(dead_code)]
     fn pawns_can_capture_pawn_east(&self, c: Color) -> Bitboard {
         match c {
             White => self.piece_bb(Some(White), Pawn) & self.pawn_west_attack_squares(Black),
@@ -223,6 +716,7 @@ impl Board {
 
     /// Returns a bitboard marking the pawns of color `c` that
     /// can capture another pawn to the west under pseudo-legal move generation
+    #[allow(dead_code)]
     fn pawns_can_capture_pawn_west(&self, c: Color) -> Bitboard {
         match c {
             White => self.piece_bb(Some(White), Pawn) & self.pawn_east_attack_squares(Black),
@@ -232,101 +726,66 @@ impl Board {
 
     /// Returns a bitboard marking the pawns of color `c` that
     /// can capture another pawn in any direction under pseudo-legal move generation
+    #[allow(dead_code)]
     fn pawns_can_capture_pawn(&self, c: Color) -> Bitboard {
         self.piece_bb(Some(c), Pawn) & self.pawn_attack_squares(!c)
     }
 
-    /// Returns a bitboard marking ray attacks in direction `d` from
-    /// square `s`. Ray attacks flow in direction `d`, but stop when
-    /// a piece blocks the ray. The attack set includes the stopping piece.
-    fn ray_attacks(&self, d: Dir, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
-        let occupied_bb = match occupied_bb {
-            Some(b) => b,
-            None => self.occupied_bb,
-        };
-        let mut attacks = tables::RAY_ATTACKS[d as usize][s as usize];
-        let blocking = attacks & occupied_bb;
-        let blocker = if d.pos() {
-            blocking.bit_scan()
-        } else {
-            blocking.bit_scan_reverse()
-        };
-        if let Some(blocker) = blocker {
-            attacks ^= tables::RAY_ATTACKS[d as usize][blocker as usize];
-        }
-        attacks
-    }
-
-    /// Returns a bitboard marking diagonal attacks
-    /// (positive slope) from square `s`
-    fn diag_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
-        self.ray_attacks(Noea, s, occupied_bb) | self.ray_attacks(Sowe, s, occupied_bb)
-    }
-
-    /// Returns a bitboard marking antidiagonal attacks
-    /// (negative slope) from square `s`
-    fn anti_diag_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
-        self.ray_attacks(Nowe, s, occupied_bb) | self.ray_attacks(Soea, s, occupied_bb)
-    }
-
-    /// Returns a bitboard marking file attacks
-    /// (same number) from square `s`
-    fn file_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
-        self.ray_attacks(Nort, s, occupied_bb) | self.ray_attacks(Sout, s, occupied_bb)
-    }
-
-    /// Returns a bitboard marking rank attacks
-    /// (same letter) from square `s`
-    fn rank_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
-        self.ray_attacks(East, s, occupied_bb) | self.ray_attacks(West, s, occupied_bb)
-    }
-
     /// Returns a bitboard marking bishop attacks
     /// from square `s` under pseudo-legal move generation
-    fn bishop_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
-        self.diag_attacks(s, occupied_bb) | self.anti_diag_attacks(s, occupied_bb)
+    pub fn bishop_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
+        magic::bishop_attacks(s, occupied_bb.unwrap_or(self.occupied_bb))
     }
 
     /// Returns a bitboard marking rook attacks
     /// from square `s` under pseudo-legal move generation
-    fn rook_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
-        self.file_attacks(s, occupied_bb) | self.rank_attacks(s, occupied_bb)
+    pub fn rook_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
+        magic::rook_attacks(s, occupied_bb.unwrap_or(self.occupied_bb))
     }
 
     /// Returns a bitboard marking queen attacks
     /// from square `s` under pseudo-legal move generation
-    fn queen_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
+    pub fn queen_attacks(&self, s: Square, occupied_bb: Option<Bitboard>) -> Bitboard {
         self.rook_attacks(s, occupied_bb) | self.bishop_attacks(s, occupied_bb)
     }
 
     /// Returns a bitboard marking pawn attacks
     /// from square `s` of a pawn of color `c` under pseudo-legal move generation
-    fn pawn_attacks(s: Square, c: Color) -> Bitboard {
+    pub fn pawn_attacks(s: Square, c: Color) -> Bitboard {
         tables::PAWN_ATTACKS[c as usize][s as usize]
     }
 
     /// Returns a bitboard marking knight attacks
     /// from square `s` under pseudo-legal move generation
-    fn knight_attacks(s: Square) -> Bitboard {
+    pub fn knight_attacks(s: Square) -> Bitboard {
         tables::KNIGHT_ATTACKS[s as usize]
     }
 
     /// Returns a bitboard marking king attacks
     /// from square `s` under pseudo-legal move generation
-    fn king_attacks(s: Square) -> Bitboard {
+    pub fn king_attacks(s: Square) -> Bitboard {
         tables::KING_ATTACKS[s as usize]
     }
 
     /// Returns a bitboard marking squares with pieces present that
-    /// attack square `s` under pseudo-legal move generation
-    fn attacks_to(&self, s: Square, by_color: Color) -> Bitboard {
+    /// attack square `s` under pseudo-legal move generation. If `occupied_bb`
+    /// is given, it is used in place of the board's occupancy, both for
+    /// blocking sliders and for which pieces are still on the board.
+    pub fn attacks_to(
+        &self,
+        s: Square,
+        by_color: Color,
+        occupied_bb: Option<Bitboard>,
+    ) -> Bitboard {
+        let occupied = occupied_bb.unwrap_or(self.occupied_bb);
         self.color_bb(by_color)
+            & occupied
             & (Board::pawn_attacks(s, !by_color) & self.piece_bb(None, Pawn)
                 | Board::knight_attacks(s) & self.piece_bb(None, Knight)
                 | Board::king_attacks(s) & self.piece_bb(None, King)
-                | self.bishop_attacks(s, None)
+                | self.bishop_attacks(s, occupied_bb)
                     & (self.piece_bb(None, Bishop) | self.piece_bb(None, Queen))
-                | self.rook_attacks(s, None)
+                | self.rook_attacks(s, occupied_bb)
                     & (self.piece_bb(None, Rook) | self.piece_bb(None, Queen)))
     }
 
@@ -359,7 +818,7 @@ impl Board {
     /// Returns a bitboard marking the squares in between `from` and `to` along
     /// a ray in one of the eight cardinal directions. Returns an empty bitboard
     /// if `from` and `to` are not along a cardinal direction.
-    fn in_between(from: Square, to: Square) -> Bitboard {
+    pub fn in_between(from: Square, to: Square) -> Bitboard {
         tables::IN_BETWEEN[from as usize][to as usize]
     }
 
@@ -379,8 +838,7 @@ impl Board {
 
         // Same thing but for bishop rays
         let op_bq = self.piece_bb(Some(!on_color), Bishop) | self.piece_bb(Some(!on_color), Queen);
-        let mut pinned: Bitboard = Bitboard(0);
-        // xray rook attacks from our king, past our pieces as blockers,
+        // xray bishop attacks from our king, past our pieces as blockers,
         // to oponent's pieces
         let pinners = self.xray_bishop_attacks(self.color_bb(on_color), king_square) & op_bq;
         // for each pinner
@@ -393,116 +851,195 @@ impl Board {
     }
 
     /// Makes the move `m`, updating this board's internal state
-    /// This function assumes `m` is a valid move
+    /// This function assumes `m` is a valid move. The state needed to
+    /// take the move back is pushed onto the undo stack, see `unmake_move`.
     pub fn make_move_mut(&mut self, m: &CMove) {
-        use Square::*;
-        let promo_piece = m.is_promo();
         let from = m.get_from();
-        // a one on the from square, else zeroes
-        let from_bb = from.as_bitboard();
-        // a one on the to square, else zeroes
-        let to_bb = m.get_to().as_bitboard();
-        // ones on the from and to squares, else zeroes
-        let from_to_bb = from_bb ^ to_bb;
+        let to = m.get_to();
         // Assuming this is a valid move and there is a piece on the square
-        let CPiece(piece, color) = self.piece_on_square(m.get_from()).unwrap();
+        let CPiece(piece, color) = self.piece_on_square(from).unwrap();
 
-        if m.is_king_castle() {
-            self.piece_bb[piece as usize] ^= from_to_bb;
-            let (rook_from_to_bb, castle_mask) = if let White = color {
-                (Bitboard(1 << 7) | Bitboard(1 << 5), 12)
-            } else {
-                (Bitboard(1 << 63) | Bitboard(1 << 61), 3)
-            };
-            self.piece_bb[Rook as usize] ^= rook_from_to_bb;
-            self.castling_rights &= castle_mask;
-            return;
-        } else if m.is_queen_castle() {
-            self.piece_bb[piece as usize] ^= from_to_bb;
-            let (rook_from_to_bb, castle_mask) = if let White = color {
-                (Bitboard(1) | Bitboard(1 >> 3), 12)
+        let captured = if m.is_ep_capture() {
+            Some(Pawn)
+        } else if m.is_capture() {
+            self.piece_on_square(to).map(|CPiece(p, _)| p)
+        } else {
+            None
+        };
+
+        self.undo_stack.push(Undo {
+            m: *m,
+            hash: self.hash,
+            captured,
+            en_passant_bb: self.en_passant_bb,
+            fifty_move_rule_counter: self.fifty_move_rule_counter,
+            castling_rights: self.castling_rights,
+        });
+        // Take out the keys for the state that is about to change
+        self.hash ^= self.state_key();
+
+        if let Some(captured) = captured {
+            // An en passant capture takes the pawn that just double pushed,
+            // which isn't on the to square
+            let captured_square = if m.is_ep_capture() {
+                self.en_passant_bb.bit_scan().unwrap()
             } else {
-                (Bitboard(56) | Bitboard(1 << 53), 3)
+                to
             };
-            self.piece_bb[Rook as usize] ^= rook_from_to_bb;
-            self.castling_rights &= castle_mask;
-            return;
+            self.toggle_piece(captured, !color, captured_square);
         }
 
-        if m.is_capture() {
+        // Move the piece, promoting it if needed
+        self.toggle_piece(piece, color, from);
+        self.toggle_piece(m.is_promo().unwrap_or(piece), color, to);
+
+        if let Some((rook_from, rook_to)) = Board::castle_rook_squares(m) {
+            self.toggle_piece(Rook, color, rook_from);
+            self.toggle_piece(Rook, color, rook_to);
+        }
+
+        if captured.is_some() || piece == Pawn {
             self.fifty_move_rule_counter = 0;
-            // If captured piece is different than piece, this is correct,
-            // otherwise the to square will be set to 0 instead of 1
-            self.piece_bb[piece as usize] ^= from_to_bb;
-            // Update from piece's color bit
-            self.piece_bb[6 + color as usize] ^= from_to_bb;
-
-            let CPiece(captured_piece, captured_color) = self.piece_on_square(m.get_to()).unwrap();
-            // If captured piece is different than piece, we update
-            // captured piece bitboard normally, otherwise we flip the bit that was incorrect
-            self.piece_bb[captured_piece as usize] ^= to_bb;
-
-            // Promote the piece
-            if let Some(promo_piece) = promo_piece {
-                self.piece_bb[piece as usize] ^= to_bb;
-                self.piece_bb[promo_piece as usize] ^= to_bb;
-            }
-
-            // update captured color bitboard
-            self.piece_bb[6 + captured_color as usize] ^= to_bb;
-            // occupied bitboard has new empty square
-            self.occupied_bb ^= from_bb;
-            // empty bitboard has new empty square
-            self.empty_bb ^= from_bb;
         } else {
-            // This is a promotion
-            if let Some(promo_piece) = promo_piece {
-                // Update prev piece bitboard
-                self.piece_bb[piece as usize] ^= from_bb;
-                // Updated promo piece bitboard
-                self.piece_bb[promo_piece as usize] ^= to_bb;
-            } else {
-                // update piece bitboard
-                self.piece_bb[piece as usize] ^= from_to_bb;
-            }
-
-            if let Pawn = piece {
-                self.fifty_move_rule_counter = 0;
-            } else {
-                self.fifty_move_rule_counter += 1;
-            }
-            // update color bitboard
-            self.piece_bb[6 + color as usize] ^= from_to_bb;
-            // occupied bitboard has new empty square
-            self.occupied_bb ^= from_bb;
-            // empty bitboard has new empty square
-            self.empty_bb ^= from_bb;
+            self.fifty_move_rule_counter = self.fifty_move_rule_counter.saturating_add(1);
         }
 
         if m.is_pawn_dpush() {
-            self.en_passant_bb = m.get_to().as_bitboard();
+            self.en_passant_bb = to.as_bitboard();
         } else {
             self.en_passant_bb = Bitboard(0);
         }
-        // update castling rights if king moved
-        match (piece, color) {
-            (King, White) => self.castling_rights &= 12,
-            (King, Black) => self.castling_rights &= 3,
-            _ => (),
-        };
-        // update castling rights if rook moved
-        match (piece, from) {
-            (Rook, A1) => self.castling_rights &= 13,
-            (Rook, H1) => self.castling_rights &= 15,
-            (Rook, A8) => self.castling_rights &= 7,
-            (Rook, H8) => self.castling_rights &= 11,
-            _ => (),
+
+        // A king or rook leaving its square, or a rook being captured on
+        // its square, removes the matching castling rights
+        self.castling_rights &= Board::castling_rights_kept(from) & Board::castling_rights_kept(to);
+
+        self.ply += 1;
+        self.side_to_move = !color;
+        self.hash ^= self.state_key();
+    }
+
+    /// Takes back the last move made by `make_move_mut`, restoring the board
+    /// to exactly the state it was in before. Returns the move taken back, or
+    /// `None` if there are no moves to take back.
+    pub fn unmake_move(&mut self) -> Option<CMove> {
+        let undo = self.undo_stack.pop()?;
+        let m = undo.m;
+        let from = m.get_from();
+        let to = m.get_to();
+        let color = !self.side_to_move;
+
+        // The piece on the to square is the promoted piece for promotions
+        let CPiece(moved, _) = self.piece_on_square(to).unwrap();
+        self.toggle_piece(moved, color, to);
+        self.toggle_piece(
+            if m.is_promo().is_some() { Pawn } else { moved },
+            color,
+            from,
+        );
+
+        if let Some((rook_from, rook_to)) = Board::castle_rook_squares(&m) {
+            self.toggle_piece(Rook, color, rook_to);
+            self.toggle_piece(Rook, color, rook_from);
+        }
+
+        if let Some(captured) = undo.captured {
+            let captured_square = if m.is_ep_capture() {
+                undo.en_passant_bb.bit_scan().unwrap()
+            } else {
+                to
+            };
+            self.toggle_piece(captured, !color, captured_square);
+        }
+
+        self.en_passant_bb = undo.en_passant_bb;
+        self.fifty_move_rule_counter = undo.fifty_move_rule_counter;
+        self.castling_rights = undo.castling_rights;
+        self.ply -= 1;
+        self.side_to_move = color;
+        self.hash = undo.hash;
+        Some(m)
+    }
+
+    /// Adds piece `p` of color `c` to square `s` if the square is empty,
+    /// or removes it if it is there
+    fn toggle_piece(&mut self, p: Piece, c: Color, s: Square) {
+        let bb = s.as_bitboard();
+        self.piece_bb[p as usize] ^= bb;
+        self.piece_bb[6 + c as usize] ^= bb;
+        self.occupied_bb ^= bb;
+        self.empty_bb ^= bb;
+        self.hash ^= zobrist::PIECE_KEYS[c as usize * 6 + p as usize][s as usize];
+    }
+
+    /// Returns the Zobrist hash of this position. Two positions with the same
+    /// pieces, side to move, castling rights and en passant captures have
+    /// the same hash.
+    pub fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Returns the Zobrist hash of this position worked out from scratch
+    fn compute_hash(&self) -> u64 {
+        let mut hash = self.state_key();
+        for s in self.occupied_bb {
+            // Every occupied square has a piece
+            let CPiece(p, c) = self.piece_on_square(s).unwrap();
+            hash ^= zobrist::PIECE_KEYS[c as usize * 6 + p as usize][s as usize];
+        }
+        hash
+    }
+
+    /// Returns the part of the Zobrist hash for the side to move, castling
+    /// rights and en passant file. Like Polyglot, the en passant file only
+    /// counts when a pawn of the side to move is beside the pawn that double
+    /// pushed, so a double push no pawn can take doesn't make the position
+    /// look new.
+    fn state_key(&self) -> u64 {
+        let mut key = zobrist::CASTLING_KEYS[self.castling_rights as usize];
+        if let Some(s) = self.en_passant_bb.bit_scan() {
+            let beside =
+                Bitboard::east_one(self.en_passant_bb) | Bitboard::west_one(self.en_passant_bb);
+            if (beside & self.piece_bb(Some(self.side_to_move), Pawn)).occupied() {
+                key ^= zobrist::EN_PASSANT_KEYS[s.file() as usize];
+            }
+        }
+        if let Black = self.side_to_move {
+            key ^= zobrist::SIDE_KEY;
+        }
+        key
+    }
+
+    /// Returns the from and to squares of the rook if `m` is a castle
+    fn castle_rook_squares(m: &CMove) -> Option<(Square, Square)> {
+        use Square::*;
+        match (m.get_to(), m.is_king_castle(), m.is_queen_castle()) {
+            (G1, true, _) => Some((H1, F1)),
+            (C1, _, true) => Some((A1, D1)),
+            (G8, true, _) => Some((H8, F8)),
+            (C8, _, true) => Some((A8, D8)),
+            _ => None,
+        }
+    }
+
+    /// Returns a mask of the castling rights that survive a move
+    /// to or from square `s`
+    fn castling_rights_kept(s: Square) -> u8 {
+        use Square::*;
+        match s {
+            E1 => !(WKING_SIDE_MASK | WQUEEN_SIDE_MASK),
+            H1 => !WKING_SIDE_MASK,
+            A1 => !WQUEEN_SIDE_MASK,
+            E8 => !(BKING_SIDE_MASK | BQUEEN_SIDE_MASK),
+            H8 => !BKING_SIDE_MASK,
+            A8 => !BQUEEN_SIDE_MASK,
+            _ => !0,
         }
     }
 
     /// Returns `Some(p)` if there exists a piece `p` on square `s`,
     /// otherwise None
-    fn piece_on_square(&self, s: Square) -> Option<CPiece> {
+    pub fn piece_on_square(&self, s: Square) -> Option<CPiece> {
         let bb = s.as_bitboard();
 
         let c = if (bb & self.color_bb(White)).occupied() {
@@ -524,222 +1061,586 @@ impl Board {
         panic!();
     }
 
-    /// Generates a list of moves for color `for_color`
-    /// Given the current board state
-    pub fn generate_moves(&self, for_color: Color) -> Vec<CMove> {
-        if self.fifty_move_rule_counter >= 50 {
-            return vec![];
+    /// Returns whether the king of the side to move is attacked
+    pub fn in_check(&self) -> bool {
+        let c = self.side_to_move;
+        match self.piece_bb(Some(c), King).bit_scan() {
+            Some(king_square) => self.attacks_to(king_square, !c, None).occupied(),
+            None => false,
         }
+    }
 
-        let king_bb = self.piece_bb(Some(for_color), King);
-        let king_square: Square = king_bb.bit_scan().unwrap();
-        let attacks_to_king = self.attacks_to(king_square, !for_color);
-        let checked = attacks_to_king.occupied();
-        let not_pinned = !self.pins(for_color, king_square);
-
-        if checked {
-            self.out_of_check_moves(king_square, attacks_to_king, for_color, not_pinned)
+    /// Returns whether the game has ended, and how. Checkmate and stalemate
+    /// take precedence over the draw rules, so a mate on the 100th ply
+    /// still wins.
+    pub fn status(&self) -> Status {
+        if self.generate_moves().is_empty() {
+            return match self.in_check() {
+                true => Status::Checkmate,
+                false => Status::Stalemate,
+            };
+        }
+        if self.fifty_move_rule_counter >= 100 {
+            Status::DrawFiftyMove
+        } else if self.repetitions() >= 2 {
+            Status::DrawRepetition
+        } else if self.insufficient_material() {
+            Status::DrawInsufficientMaterial
         } else {
-            (0..6)
-                .map(|i| FromPrimitive::from_i32(i).unwrap())
-                .flat_map(|piece| self.generate_piece_moves(piece, for_color, not_pinned))
-                .chain(self.castle_moves(for_color))
-                .collect()
+            Status::Ongoing
         }
     }
 
-    fn out_of_check_moves(
-        &self,
-        king_square: Square,
-        attacks_to_king: Bitboard,
-        for_color: Color,
-        not_pinned: Bitboard,
-    ) -> Vec<CMove> {
-        let king_attacks = Board::king_attacks(king_square);
-        // Can't move king to square with our own piece
-        let not_to_own_piece = king_attacks & !self.color_bb(for_color);
-        // Iterator over all king moves
-        let king_moves = not_to_own_piece.filter_map(|to| {
-            // Can't move to a square op attacks
-            if self.attacks_to(to, !for_color).occupied() {
-                None
-            } else if self.piece_on_square(to).is_some() {
-                Some(CMove::new(king_square, to, cmove::CAPTURE))
+    /// Returns how many times the current position was reached before in the
+    /// game. Only positions since the last pawn move or capture can repeat,
+    /// and only every other one has the same side to move.
+    pub fn repetitions(&self) -> usize {
+        self.undo_stack
+            .iter()
+            .rev()
+            .take(self.fifty_move_rule_counter as usize)
+            .skip(1)
+            .step_by(2)
+            .filter(|undo| undo.hash == self.hash)
+            .count()
+    }
+
+    /// Returns whether neither side can possibly checkmate: there are no
+    /// pawns, rooks or queens, and either at most one minor piece or only
+    /// bishops all on squares of the same color
+    pub fn insufficient_material(&self) -> bool {
+        let heavy =
+            self.piece_bb(None, Pawn) | self.piece_bb(None, Rook) | self.piece_bb(None, Queen);
+        if heavy.occupied() {
+            return false;
+        }
+        let knights = self.piece_bb(None, Knight);
+        let bishops = self.piece_bb(None, Bishop);
+        if (knights | bishops).pop_count() <= 1 {
+            return true;
+        }
+        knights.empty()
+            && ((bishops & bitboard::LIGHT_SQUARES).empty()
+                || (bishops & !bitboard::LIGHT_SQUARES).empty())
+    }
+
+    /// Returns the legal move written as `uci_move` in long algebraic notation,
+    /// e.g. `e2e4` or `e7e8q`, with its flags worked out from the position.
+    /// Castling is written as the king's move, e.g. `e1g1`.
+    pub fn parse_uci_move(&self, uci_move: &str) -> Result<CMove, ParseMoveError> {
+        let invalid = || ParseMoveError::InvalidNotation(uci_move.to_string());
+        let square = |i: usize| {
+            let name = uci_move.get(i..i + 2).ok_or_else(invalid)?;
+            Square::from_algebraic(name).ok_or_else(invalid)
+        };
+        let (from, to) = (square(0)?, square(2)?);
+        let promo = match uci_move.get(4..) {
+            Some("") => None,
+            Some("n") => Some(Knight),
+            Some("b") => Some(Bishop),
+            Some("r") => Some(Rook),
+            Some("q") => Some(Queen),
+            _ => return Err(invalid()),
+        };
+
+        self.generate_moves()
+            .into_iter()
+            .find(|m| m.get_from() == from && m.get_to() == to && m.is_promo() == promo)
+            .ok_or_else(|| ParseMoveError::IllegalMove(uci_move.to_string()))
+    }
+
+    /// Returns legal move `m` in Standard Algebraic Notation, e.g. `Nf3`,
+    /// `exd5`, `Rad1`, `e8=Q+` or `O-O`
+    pub fn to_san(&self, m: CMove) -> String {
+        let (from, to) = (m.get_from(), m.get_to());
+        let mut san = if m.is_king_castle() {
+            "O-O".to_string()
+        } else if m.is_queen_castle() {
+            "O-O-O".to_string()
+        } else {
+            let piece = self.piece_on_square(from).map_or(Pawn, |CPiece(p, _)| p);
+            let mut san = String::new();
+            if piece == Pawn {
+                if m.is_capture() {
+                    san.push((b'a' + from.file()) as char);
+                }
             } else {
-                Some(CMove::new(king_square, to, cmove::QUIET))
+                san.push_str(&piece.to_string());
+                // Other pieces of the same type that can move to the same square
+                let others: Vec<Square> = self
+                    .generate_moves()
+                    .into_iter()
+                    .filter(|o| o.get_to() == to && o.get_from() != from)
+                    .map(|o| o.get_from())
+                    .filter(|&s| {
+                        self.piece_on_square(s)
+                            .is_some_and(|CPiece(p, _)| p == piece)
+                    })
+                    .collect();
+                let same_file = others.iter().any(|s| s.file() == from.file());
+                let same_rank = others.iter().any(|s| s.rank() == from.rank());
+                if !others.is_empty() && (!same_file || same_rank) {
+                    san.push((b'a' + from.file()) as char);
+                }
+                if same_file {
+                    san.push((b'1' + from.rank()) as char);
+                }
             }
-        });
+            if m.is_capture() {
+                san.push('x');
+            }
+            san.push_str(&to.to_string());
+            if let Some(promo) = m.is_promo() {
+                san.push_str(&format!("={promo}"));
+            }
+            san
+        };
 
-        // only king moves can get out of double check
-        if attacks_to_king.count() > 1 {
-            king_moves.collect()
-        } else {
-            // Only one attacker
-            let attacker = attacks_to_king.bit_scan().unwrap();
-            // The pieces that can capture attacker (can't be pinned)
-            let can_capture = self.attacks_to(attacker, for_color) & not_pinned;
-            let capture_moves = can_capture.map(|from| CMove::new(from, attacker, cmove::CAPTURE));
-
-            // If the attack was the result of a dpush, we can en passant
-            let dpush_king_attack = attacks_to_king & self.en_passant_bb;
-            // No en passant capture possable
-            if dpush_king_attack.empty() {
-                king_moves.chain(capture_moves).collect()
+        let mut after = self.clone();
+        after.make_move_mut(&m);
+        if after.in_check() {
+            san.push(if after.generate_moves().is_empty() {
+                '#'
             } else {
-                let pawns_not_pinned = self.piece_bb(Some(for_color), Pawn) & not_pinned;
-                let ep_moves = Self::ep_moves(for_color, pawns_not_pinned, dpush_king_attack);
-                king_moves.chain(capture_moves).chain(ep_moves).collect()
+                '+'
+            });
+        }
+        san
+    }
+
+    /// Returns the legal move written as `san` in Standard Algebraic Notation.
+    /// Check and mate markers and annotations like `!?` are allowed but not
+    /// checked, and castling can be written with zeros as well as O's.
+    pub fn parse_san(&self, san: &str) -> Result<CMove, ParseMoveError> {
+        let invalid = || ParseMoveError::InvalidNotation(san.to_string());
+        let text = san.trim_end_matches(['+', '#', '!', '?']);
+        let moves = self.generate_moves();
+
+        let castle = match text {
+            "O-O" | "0-0" => Some(cmove::KING_CASTLE),
+            "O-O-O" | "0-0-0" => Some(cmove::QUEEN_CASTLE),
+            _ => None,
+        };
+        if let Some(flags) = castle {
+            return moves
+                .into_iter()
+                .find(|m| m.get_flags() == flags)
+                .ok_or_else(|| ParseMoveError::IllegalMove(san.to_string()));
+        }
+
+        // Piece letter, disambiguation and capture marker, target square and promotion
+        let (piece, text) = match text.chars().next() {
+            Some(c @ ('N' | 'B' | 'R' | 'Q' | 'K')) => {
+                let CPiece(piece, _) = CPiece::from_fen_char(c).ok_or_else(invalid)?;
+                (piece, &text[1..])
+            }
+            _ => (Pawn, text),
+        };
+        let (text, promo) = match text.rsplit_once('=') {
+            Some((text, promo)) => (text, Some(promo)),
+            None => match text.char_indices().last() {
+                Some((i, 'N' | 'B' | 'R' | 'Q')) => (&text[..i], Some(&text[i..])),
+                _ => (text, None),
+            },
+        };
+        let promo = match promo {
+            None => None,
+            Some("N") => Some(Knight),
+            Some("B") => Some(Bishop),
+            Some("R") => Some(Rook),
+            Some("Q") => Some(Queen),
+            Some(_) => return Err(invalid()),
+        };
+        let split = text.len().checked_sub(2).ok_or_else(invalid)?;
+        let to = text
+            .get(split..)
+            .and_then(Square::from_algebraic)
+            .ok_or_else(invalid)?;
+        let (mut file, mut rank) = (None, None);
+        for c in text[..split].trim_end_matches('x').chars() {
+            match c {
+                'a'..='h' if file.is_none() => file = Some(c as u8 - b'a'),
+                '1'..='8' if rank.is_none() => rank = Some(c as u8 - b'1'),
+                _ => return Err(invalid()),
             }
         }
+
+        let mut matching = moves.into_iter().filter(|m| {
+            let from = m.get_from();
+            m.get_to() == to
+                && m.is_promo() == promo
+                && file.is_none_or(|f| from.file() == f)
+                && rank.is_none_or(|r| from.rank() == r)
+                && self
+                    .piece_on_square(from)
+                    .is_some_and(|CPiece(p, _)| p == piece)
+        });
+        match (matching.next(), matching.next()) {
+            (Some(m), None) => Ok(m),
+            (Some(_), Some(_)) => Err(ParseMoveError::AmbiguousMove(san.to_string())),
+            (None, _) => Err(ParseMoveError::IllegalMove(san.to_string())),
+        }
     }
 
+    /// Returns the material the side to move wins or loses, in centipawns,
+    /// by making move `m` and then both sides capturing on its target square
+    /// with their least valuable piece for as long as it pays. Pieces revealed
+    /// behind others as they capture, like a rook behind a rook, join in.
+    /// Pins are ignored.
+    pub fn see(&self, m: CMove) -> i32 {
+        let (from, to) = (m.get_from(), m.get_to());
+        let Some(CPiece(mut piece, mut side)) = self.piece_on_square(from) else {
+            return 0;
+        };
+        let mut occupied = self.occupied_bb ^ from.as_bitboard();
+
+        // What each capture in the sequence wins, before the other side replies
+        let mut gains = Vec::with_capacity(32);
+        gains.push(match self.piece_on_square(to) {
+            Some(CPiece(captured, _)) => SEE_VALUES[captured as usize],
+            None if m.is_ep_capture() => {
+                let captured = Square::from_file_rank(to.file(), from.rank()).unwrap();
+                occupied ^= captured.as_bitboard();
+                SEE_VALUES[Pawn as usize]
+            }
+            None => 0,
+        });
+        if let Some(promo) = m.is_promo() {
+            gains[0] += SEE_VALUES[promo as usize] - SEE_VALUES[Pawn as usize];
+            piece = promo;
+        }
+
+        loop {
+            side = !side;
+            let attackers = self.attacks_to(to, side, Some(occupied));
+            let Some((square, attacker)) = [Pawn, Knight, Bishop, Rook, Queen, King]
+                .into_iter()
+                .find_map(|p| {
+                    let square = (attackers & self.piece_bb(None, p)).bit_scan()?;
+                    Some((square, p))
+                })
+            else {
+                break;
+            };
+            occupied ^= square.as_bitboard();
+            // The king can't capture onto a square that is still defended
+            if attacker == King && self.attacks_to(to, !side, Some(occupied)).occupied() {
+                break;
+            }
+            gains.push(SEE_VALUES[piece as usize] - gains[gains.len() - 1]);
+            piece = attacker;
+        }
+
+        // Either side can stop capturing when carrying on would lose material
+        while gains.len() > 1 {
+            let gain = gains.pop().unwrap();
+            let last = gains.last_mut().unwrap();
+            *last = -(-*last).max(gain);
+        }
+        gains[0]
+    }
+
+    /// Generates a list of legal moves for the side to move
+    /// Given the current board state
+    pub fn generate_moves(&self) -> Vec<CMove> {
+        let for_color = self.side_to_move;
+        let king_bb = self.piece_bb(Some(for_color), King);
+        let king_square: Square = king_bb.bit_scan().unwrap();
+        let attacks_to_king = self.attacks_to(king_square, !for_color, None);
+        let pinned = self.pins(for_color, king_square);
+
+        // The squares pieces other than the king can move to. Out of check this
+        // is everywhere, in check it is the attacker and the squares blocking it,
+        // and in double check only the king can move.
+        let targets = match attacks_to_king.count() {
+            0 => Bitboard(!0),
+            1 => {
+                let attacker = attacks_to_king.bit_scan().unwrap();
+                attacks_to_king | Board::in_between(king_square, attacker)
+            }
+            _ => Bitboard(0),
+        };
+
+        let mut moves = Vec::with_capacity(64);
+        self.king_moves(&mut moves, king_square, for_color);
+        if targets.occupied() {
+            for piece in [Knight, Bishop, Rook, Queen] {
+                self.generate_piece_moves(
+                    &mut moves,
+                    piece,
+                    for_color,
+                    king_square,
+                    pinned,
+                    targets,
+                );
+            }
+            self.generate_pawn_moves(&mut moves, for_color, king_square, pinned, targets);
+            self.ep_moves(&mut moves, for_color, king_square);
+        }
+        if attacks_to_king.empty() {
+            self.castle_moves(&mut moves, for_color);
+        }
+        moves
+    }
+
+    /// Returns the flag for a non-castling move to square `to`
+    fn move_flag(&self, to: Square) -> u16 {
+        if (to.as_bitboard() & self.occupied_bb).occupied() {
+            cmove::CAPTURE
+        } else {
+            cmove::QUIET
+        }
+    }
+
+    /// Returns the squares a piece on square `from` can move to without leaving
+    /// the king on `king_square` in check, given the squares `targets` that deal
+    /// with any check. A pinned piece may only move along the ray from the king
+    /// through it.
+    fn legal_targets(
+        from: Square,
+        king_square: Square,
+        pinned: Bitboard,
+        targets: Bitboard,
+    ) -> Bitboard {
+        if (from.as_bitboard() & pinned).empty() {
+            return targets;
+        }
+        (0..8)
+            .map(|d| tables::RAY_ATTACKS[d][king_square as usize])
+            .find(|ray| (*ray & from.as_bitboard()).occupied())
+            .map_or(Bitboard(0), |ray| ray & targets)
+    }
+
+    /// Generates the moves of the king on `king_square` to squares the
+    /// oponent doesn't attack
+    fn king_moves(&self, moves: &mut Vec<CMove>, king_square: Square, for_color: Color) {
+        // The king can't hide from a slider behind itself, so we take it off
+        // the board when looking for attacks
+        let occupied_bb = self.occupied_bb ^ king_square.as_bitboard();
+        moves.extend(
+            (Board::king_attacks(king_square) & !self.color_bb(for_color))
+                .filter(|to| self.attacks_to(*to, !for_color, Some(occupied_bb)).empty())
+                .map(|to| CMove::new(king_square, to, self.move_flag(to))),
+        );
+    }
+
+    /// Generates the moves of the knights, bishops, rooks or queens of color
+    /// `for_color` that move to a square in `targets`
     fn generate_piece_moves(
         &self,
+        moves: &mut Vec<CMove>,
         for_piece: Piece,
         for_color: Color,
-        not_pinned: Bitboard,
-    ) -> Vec<CMove> {
-        if let Pawn = for_piece {
-            self.generate_pawn_moves(for_color, not_pinned)
-        } else {
-            let piece_bb = self.piece_bb(Some(for_color), for_piece);
-
-            piece_bb
-                .flat_map(|from| {
-                    let can_attack = match for_piece {
-                        Knight => Board::knight_attacks(from),
-                        Bishop => self.bishop_attacks(from, None),
-                        Rook => self.rook_attacks(from, None),
-                        Queen => self.queen_attacks(from, None),
-                        King => Board::king_attacks(from),
-                        Pawn => panic!(), // Can't happen
-                    } & !self.color_bb(for_color); // Can't move to square with own piece
-
-                    can_attack.map(move |to| {
-                        let to_square_bb = to.as_bitboard();
-                        let flag = if (to_square_bb & self.occupied_bb).occupied() {
-                            cmove::CAPTURE
-                        } else {
-                            cmove::QUIET
-                        };
-                        CMove::new(from, to, flag)
-                    })
-                })
-                .collect()
-        }
+        king_square: Square,
+        pinned: Bitboard,
+        targets: Bitboard,
+    ) {
+        let piece_bb = self.piece_bb(Some(for_color), for_piece);
+
+        moves.extend(piece_bb.flat_map(|from| {
+            let can_attack = match for_piece {
+                    Knight => Board::knight_attacks(from),
+                    Bishop => self.bishop_attacks(from, None),
+                    Rook => self.rook_attacks(from, None),
+                    Queen => self.queen_attacks(from, None),
+                    Pawn | King => panic!(), // Generated separately
+                } & !self.color_bb(for_color) // Can't move to square with own piece
+                    & Board::legal_targets(from, king_square, pinned, targets);
+
+            can_attack.map(move |to| CMove::new(from, to, self.move_flag(to)))
+        }));
     }
 
-    fn generate_pawn_moves(&self, for_color: Color, not_pinned: Bitboard) -> Vec<CMove> {
+    fn generate_pawn_moves(
+        &self,
+        moves: &mut Vec<CMove>,
+        for_color: Color,
+        king_square: Square,
+        pinned: Bitboard,
+        targets: Bitboard,
+    ) {
         let op_occupied = self.color_bb(!for_color);
-        let pawn_bb = self.piece_bb(Some(for_color), Pawn) & not_pinned;
+        let pawn_bb = self.piece_bb(Some(for_color), Pawn);
         let can_push = self.pawns_can_push(for_color);
         let can_dpush = self.pawns_can_dpush(for_color);
+        let to_dir = match for_color {
+            White => Nort,
+            Black => Sout,
+        };
 
         // For every pawn
-        let regular_moves = pawn_bb.flat_map(|from| {
-            let mut moves = vec![];
-            let can_attack = Board::pawn_attacks(from, for_color) & op_occupied;
+        for from in pawn_bb {
+            let legal_targets = Board::legal_targets(from, king_square, pinned, targets);
+            let can_attack = Board::pawn_attacks(from, for_color) & op_occupied & legal_targets;
             let this_pawn_bb = from.as_bitboard();
 
             // If this pawn can be single pushed
             if (can_push & this_pawn_bb).occupied() {
-                let to_dir = match for_color {
-                    White => Nort,
-                    Black => Sout,
-                };
                 // We can unwrap since we know this pawn can be pushed
                 let to = from.translate(to_dir, 1).unwrap();
-                moves.push(CMove::new(from, to, cmove::QUIET));
+                if (to.as_bitboard() & legal_targets).occupied() {
+                    Board::push_pawn_move(moves, from, to, false);
+                }
             }
 
             // If this pawn can be double pushed
             if (can_dpush & this_pawn_bb).occupied() {
-                let to_dir = match for_color {
-                    White => Nort,
-                    Black => Sout,
-                };
                 // We can unwrap since we know this pawn can be pushed
                 let to = from.translate(to_dir, 2).unwrap();
-                moves.push(CMove::new(from, to, cmove::PAWN_DPUSH));
+                if (to.as_bitboard() & legal_targets).occupied() {
+                    moves.push(CMove::new(from, to, cmove::PAWN_DPUSH));
+                }
             }
 
             // For every piece this pawn attacks
-            can_attack.for_each(|to| moves.push(CMove::new(from, to, cmove::CAPTURE)));
-            moves
-        });
-
-        let ep_moves = Self::ep_moves(for_color, pawn_bb, self.en_passant_bb);
-        regular_moves.chain(ep_moves).collect()
+            for to in can_attack {
+                Board::push_pawn_move(moves, from, to, true);
+            }
+        }
     }
 
-    fn ep_moves(for_color: Color, with_pawns: Bitboard, pawn_dpushed: Bitboard) -> Vec<CMove> {
-        let mut moves = vec![];
-        // If our pawn lies to the east of the dpushed pawn, we en passant west
-        let ep_capture_west_pawn = Bitboard::east_one(pawn_dpushed) & with_pawns;
-        // If our pawn lies to the west of the dpushed pawn, we en passant west
-        let ep_capture_east_pawn = Bitboard::west_one(pawn_dpushed) & with_pawns;
-        // We can en passant west
-        if ep_capture_west_pawn.occupied() {
-            let from = ep_capture_west_pawn.bit_scan().unwrap();
-            let to = match for_color {
-                White => from.translate(Nowe, 1),
-                Black => from.translate(Sowe, 1),
-            }
-            .unwrap();
-            moves.push(CMove::new(from, to, cmove::EP_CAPTURE));
+    /// Adds a pawn move from `from` to `to` to `moves`, or all four promotions
+    /// if `to` is on the last rank
+    fn push_pawn_move(moves: &mut Vec<CMove>, from: Square, to: Square, capture: bool) {
+        if to.rank() == 0 || to.rank() == 7 {
+            let promos = if capture {
+                [
+                    cmove::QUEEN_PROMO_CAPTURE,
+                    cmove::ROOK_PROMO_CAPTURE,
+                    cmove::BISHOP_PROMO_CAPTURE,
+                    cmove::KNIGHT_PROMO_CAPTURE,
+                ]
+            } else {
+                [
+                    cmove::QUEEN_PROMO,
+                    cmove::ROOK_PROMO,
+                    cmove::BISHOP_PROMO,
+                    cmove::KNIGHT_PROMO,
+                ]
+            };
+            moves.extend(promos.map(|flag| CMove::new(from, to, flag)));
+        } else {
+            let flag = if capture {
+                cmove::CAPTURE
+            } else {
+                cmove::QUIET
+            };
+            moves.push(CMove::new(from, to, flag));
         }
-        // We can en passant east
-        if ep_capture_east_pawn.occupied() {
-            let from = ep_capture_west_pawn.bit_scan().unwrap();
-            let to = match for_color {
-                White => from.translate(Noea, 1),
-                Black => from.translate(Soea, 1),
-            }
-            .unwrap();
-            moves.push(CMove::new(from, to, cmove::EP_CAPTURE));
+    }
+
+    /// Generates the en passant captures of color `for_color`. Since en passant
+    /// removes two pawns from the same rank, pins are checked by looking for
+    /// attacks on the king after the capture instead of with `pins`.
+    fn ep_moves(&self, moves: &mut Vec<CMove>, for_color: Color, king_square: Square) {
+        let pawn_dpushed = self.en_passant_bb;
+        let Some(captured_square) = pawn_dpushed.bit_scan() else {
+            return;
+        };
+        let with_pawns = self.piece_bb(Some(for_color), Pawn);
+        // The square the capturing pawn lands on is behind the dpushed pawn
+        let to = match for_color {
+            White => captured_square.translate(Nort, 1),
+            Black => captured_square.translate(Sout, 1),
         }
-        moves
+        .unwrap();
+
+        // Our pawns to the east and west of the dpushed pawn
+        let capturers =
+            (Bitboard::east_one(pawn_dpushed) | Bitboard::west_one(pawn_dpushed)) & with_pawns;
+        moves.extend(
+            capturers
+                .filter(|from| {
+                    let occupied_bb =
+                        self.occupied_bb ^ from.as_bitboard() ^ pawn_dpushed ^ to.as_bitboard();
+                    (self.attacks_to(king_square, !for_color, Some(occupied_bb)) & !pawn_dpushed)
+                        .empty()
+                })
+                .map(|from| CMove::new(from, to, cmove::EP_CAPTURE)),
+        );
     }
 
-    fn castle_moves(&self, for_color: Color) -> Vec<CMove> {
+    /// Generates the castling moves of color `for_color`. Assumes the king
+    /// isn't in check.
+    fn castle_moves(&self, moves: &mut Vec<CMove>, for_color: Color) {
         use Square::*;
-        let mut moves = vec![];
         match for_color {
             White => {
                 // can king-side castle
-                if self.castling_rights & WKING_SIDE_MASK > 0 {
-                    if (self.attacks_to(F1, Black) | self.attacks_to(G1, Black)).empty()
-                        && (self.occupied_bb & Bitboard(1 << 6 | 1 << 5)).empty()
-                    {
-                        moves.push(CMove::new(E1, G1, cmove::KING_CASTLE));
-                    }
+                if self.castling_rights & WKING_SIDE_MASK > 0
+                    && (self.occupied_bb & Bitboard(1 << 5 | 1 << 6)).empty()
+                    && (self.attacks_to(F1, Black, None) | self.attacks_to(G1, Black, None)).empty()
+                {
+                    moves.push(CMove::new(E1, G1, cmove::KING_CASTLE));
                 }
-                if self.castling_rights & BKING_SIDE_MASK > 0 {
-                    if (self.attacks_to(C1, Black) | self.attacks_to(D1, Black)).empty()
-                        && (self.occupied_bb & Bitboard(1 << 3 | 1 << 4)).empty()
-                    {
-                        moves.push(CMove::new(E1, C1, cmove::QUEEN_CASTLE));
-                    }
+                // can queen-side castle
+                if self.castling_rights & WQUEEN_SIDE_MASK > 0
+                    && (self.occupied_bb & Bitboard(1 << 1 | 1 << 2 | 1 << 3)).empty()
+                    && (self.attacks_to(C1, Black, None) | self.attacks_to(D1, Black, None)).empty()
+                {
+                    moves.push(CMove::new(E1, C1, cmove::QUEEN_CASTLE));
                 }
             }
             Black => {
                 // can king-side castle
-                if self.castling_rights & BKING_SIDE_MASK > 0 {
-                    if (self.attacks_to(F8, White) | self.attacks_to(G8, White)).empty()
-                        && (self.occupied_bb & Bitboard(1 << 62 | 1 << 61)).empty()
-                    {
-                        moves.push(CMove::new(E8, G8, cmove::KING_CASTLE));
-                    }
+                if self.castling_rights & BKING_SIDE_MASK > 0
+                    && (self.occupied_bb & Bitboard(1 << 61 | 1 << 62)).empty()
+                    && (self.attacks_to(F8, White, None) | self.attacks_to(G8, White, None)).empty()
+                {
+                    moves.push(CMove::new(E8, G8, cmove::KING_CASTLE));
                 }
-                if self.castling_rights & BKING_SIDE_MASK > 0 {
-                    if (self.attacks_to(C1, Black) | self.attacks_to(D1, Black)).empty()
-                        && (self.occupied_bb & Bitboard(1 << 58 | 1 << 59)).empty()
-                    {
-                        moves.push(CMove::new(E8, C8, cmove::KING_CASTLE));
-                    }
+                // can queen-side castle
+                if self.castling_rights & BQUEEN_SIDE_MASK > 0
+                    && (self.occupied_bb & Bitboard(1 << 57 | 1 << 58 | 1 << 59)).empty()
+                    && (self.attacks_to(C8, White, None) | self.attacks_to(D8, White, None)).empty()
+                {
+                    moves.push(CMove::new(E8, C8, cmove::QUEEN_CASTLE));
                 }
             }
         }
+    }
+
+    /// Counts the leaf nodes of the tree of legal moves `depth` plies deep
+    /// from this position. Comparing against known counts is the standard way
+    /// of validating move generation.
+    ///
+    /// The known counts for a number of positions are listed at:
+    /// <https://www.chessprogramming.org/Perft_Results>
+    pub fn perft(&mut self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let moves = self.generate_moves();
+        // Bulk count the last ply
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+
         moves
+            .iter()
+            .map(|m| {
+                self.make_move_mut(m);
+                let nodes = self.perft(depth - 1);
+                self.unmake_move();
+                nodes
+            })
+            .sum()
+    }
+
+    /// Returns the perft node count `depth - 1` plies below each legal move in
+    /// this position, so that a wrong count can be narrowed down to a move.
+    /// `depth` must be at least 1.
+    pub fn divide(&mut self, depth: u32) -> Vec<(CMove, u64)> {
+        let moves = self.generate_moves();
+        moves
+            .into_iter()
+            .map(|m| {
+                self.make_move_mut(&m);
+                let nodes = self.perft(depth.saturating_sub(1));
+                self.unmake_move();
+                (m, nodes)
+            })
+            .collect()
     }
 }
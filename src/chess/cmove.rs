@@ -3,6 +3,7 @@ use super::utils::{
     Square,
 };
 use num::FromPrimitive;
+use std::fmt;
 
 pub const QUIET: u16 = 0;
 pub const PAWN_DPUSH: u16 = 1;
@@ -26,6 +27,17 @@ impl CMove {
         CMove(((flags & 0xf) << 12) | ((from as u16) << 6) | (to as u16))
     }
 
+    /// Returns the move from its packed 16 bit form, as given by `to_bits`
+    pub fn from_bits(bits: u16) -> CMove {
+        CMove(bits)
+    }
+
+    /// Returns the move packed into 16 bits: 4 bits of flags, 6 bits of
+    /// from square and 6 bits of to square
+    pub fn to_bits(&self) -> u16 {
+        self.0
+    }
+
     pub fn get_from(&self) -> Square {
         // Index can't be more than 63
         FromPrimitive::from_u16((self.0 >> 6) & 0x3f).unwrap()
@@ -41,7 +53,7 @@ impl CMove {
     }
 
     pub fn set_from(&mut self, from: Square) {
-        self.0 = (self.0 & 0xff3f) | ((from as u16) << 6);
+        self.0 = (self.0 & 0xf03f) | ((from as u16) << 6);
     }
 
     pub fn set_to(&mut self, to: Square) {
@@ -49,21 +61,22 @@ impl CMove {
     }
 
     pub fn is_capture(&self) -> bool {
-        self.0 & CAPTURE != 0
+        self.get_flags() & CAPTURE != 0
     }
 
     pub fn is_ep_capture(&self) -> bool {
-        self.0 == EP_CAPTURE
+        self.get_flags() == EP_CAPTURE
     }
 
     pub fn is_pawn_dpush(&self) -> bool {
-        self.0 == PAWN_DPUSH
+        self.get_flags() == PAWN_DPUSH
     }
 
     pub fn is_promo(&self) -> Option<Piece> {
-        if self.0 & 8 > 0 {
+        let flags = self.get_flags();
+        if flags & 8 > 0 {
             // Lowest 2 bits
-            Some(match self.0 & 3 {
+            Some(match flags & 3 {
                 0 => Knight,
                 1 => Bishop,
                 2 => Rook,
@@ -76,10 +89,27 @@ impl CMove {
     }
 
     pub fn is_king_castle(&self) -> bool {
-        self.0 == KING_CASTLE
+        self.get_flags() == KING_CASTLE
     }
 
     pub fn is_queen_castle(&self) -> bool {
-        self.0 == QUEEN_CASTLE
+        self.get_flags() == QUEEN_CASTLE
+    }
+
+    /// Returns the move in long algebraic notation as used by UCI, e.g.
+    /// `e2e4`, `e1g1` for castling or `e7e8q` for a promotion
+    pub fn to_uci(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Formats the move in long algebraic notation, e.g. `e2e4` or `e7e8q`
+impl fmt::Display for CMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.get_from(), self.get_to())?;
+        match self.is_promo() {
+            Some(promo) => write!(f, "{}", promo.to_string().to_ascii_lowercase()),
+            None => Ok(()),
+        }
     }
 }
@@ -1,26 +1,787 @@
+use super::bitboard::{self, Bitboard};
 use super::board::Board;
 use super::cmove::CMove;
-use std::i32;
+use super::movepick::{History, Killers, MovePicker};
+use super::timeman::TimeManager;
+use super::tt::{Bound, TranspositionTable};
+use super::utils::{Color, Piece, Square};
+use std::ops::{Add, AddAssign, Mul, Sub};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use Color::*;
+use Piece::*;
 
-fn evaluate() -> i32 {
-    1
+/// A pair of middlegame and endgame scores in centipawns. The final
+/// evaluation blends the two by how much material is left on the board.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Score {
+    mg: i32,
+    eg: i32,
 }
 
-fn alpha_beta(mut alpha: i32, beta: i32, depth: i32, moves: &Vec<CMove>, board: &mut Board) -> i32 {
-    if depth == 0 {
-        return evaluate();
+const fn s(mg: i32, eg: i32) -> Score {
+    Score { mg, eg }
+}
+
+impl Add for Score {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        s(self.mg + rhs.mg, self.eg + rhs.eg)
+    }
+}
+
+impl AddAssign for Score {
+    fn add_assign(&mut self, rhs: Self) {
+        self.mg += rhs.mg;
+        self.eg += rhs.eg;
+    }
+}
+
+impl Sub for Score {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        s(self.mg - rhs.mg, self.eg - rhs.eg)
+    }
+}
+
+impl Mul<i32> for Score {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        s(self.mg * rhs, self.eg * rhs)
+    }
+}
+
+/// Material values indexed by `Piece`
+const PIECE_VALUES: [Score; 6] = [
+    s(82, 94),
+    s(337, 281),
+    s(365, 297),
+    s(477, 512),
+    s(1025, 936),
+    s(0, 0),
+];
+
+/// How much each piece counts towards the game phase, indexed by `Piece`.
+/// The phase is 24 with all pieces on the board and 0 with only pawns and kings.
+const PHASE_WEIGHTS: [i32; 6] = [0, 1, 1, 2, 4, 0];
+const MAX_PHASE: i32 = 24;
+
+// Piece-square tables, from white's point of view with the 8th rank
+// on the first line so that they read like a board. Indexing with
+// `square ^ 56` gives the value for a white piece and indexing with
+// `square` gives the value for a black piece.
+#[rustfmt::skip]
+const PAWN_MG: [i32; 64] = [
+     0,  0,   0,   0,   0,   0,  0,  0,
+    50, 50,  50,  50,  50,  50, 50, 50,
+    10, 10,  20,  30,  30,  20, 10, 10,
+     5,  5,  10,  25,  25,  10,  5,  5,
+     0,  0,   0,  20,  20,   0,  0,  0,
+     5, -5, -10,   0,   0, -10, -5,  5,
+     5, 10,  10, -20, -20,  10, 10,  5,
+     0,  0,   0,   0,   0,   0,  0,  0,
+];
+
+#[rustfmt::skip]
+const PAWN_EG: [i32; 64] = [
+     0,  0,  0,  0,  0,  0,  0,  0,
+    60, 60, 60, 60, 60, 60, 60, 60,
+    35, 35, 35, 35, 35, 35, 35, 35,
+    20, 20, 20, 20, 20, 20, 20, 20,
+    10, 10, 10, 10, 10, 10, 10, 10,
+     5,  5,  5,  5,  5,  5,  5,  5,
+     0,  0,  0,  0,  0,  0,  0,  0,
+     0,  0,  0,  0,  0,  0,  0,  0,
+];
+
+#[rustfmt::skip]
+const KNIGHT_PST: [i32; 64] = [
+    -50, -40, -30, -30, -30, -30, -40, -50,
+    -40, -20,   0,   0,   0,   0, -20, -40,
+    -30,   0,  10,  15,  15,  10,   0, -30,
+    -30,   5,  15,  20,  20,  15,   5, -30,
+    -30,   0,  15,  20,  20,  15,   0, -30,
+    -30,   5,  10,  15,  15,  10,   5, -30,
+    -40, -20,   0,   5,   5,   0, -20, -40,
+    -50, -40, -30, -30, -30, -30, -40, -50,
+];
+
+#[rustfmt::skip]
+const BISHOP_PST: [i32; 64] = [
+    -20, -10, -10, -10, -10, -10, -10, -20,
+    -10,   0,   0,   0,   0,   0,   0, -10,
+    -10,   0,   5,  10,  10,   5,   0, -10,
+    -10,   5,   5,  10,  10,   5,   5, -10,
+    -10,   0,  10,  10,  10,  10,   0, -10,
+    -10,  10,  10,  10,  10,  10,  10, -10,
+    -10,   5,   0,   0,   0,   0,   5, -10,
+    -20, -10, -10, -10, -10, -10, -10, -20,
+];
+
+#[rustfmt::skip]
+const ROOK_PST: [i32; 64] = [
+     0,  0,  0,  0,  0,  0,  0,  0,
+     5, 10, 10, 10, 10, 10, 10,  5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+    -5,  0,  0,  0,  0,  0,  0, -5,
+     0,  0,  0,  5,  5,  0,  0,  0,
+];
+
+#[rustfmt::skip]
+const QUEEN_PST: [i32; 64] = [
+    -20, -10, -10, -5, -5, -10, -10, -20,
+    -10,   0,   0,  0,  0,   0,   0, -10,
+    -10,   0,   5,  5,  5,   5,   0, -10,
+     -5,   0,   5,  5,  5,   5,   0,  -5,
+      0,   0,   5,  5,  5,   5,   0,  -5,
+    -10,   5,   5,  5,  5,   5,   0, -10,
+    -10,   0,   5,  0,  0,   0,   0, -10,
+    -20, -10, -10, -5, -5, -10, -10, -20,
+];
+
+#[rustfmt::skip]
+const KING_MG: [i32; 64] = [
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -30, -40, -40, -50, -50, -40, -40, -30,
+    -20, -30, -30, -40, -40, -30, -30, -20,
+    -10, -20, -20, -20, -20, -20, -20, -10,
+     20,  20,   0,   0,   0,   0,  20,  20,
+     20,  30,  10,   0,   0,  10,  30,  20,
+];
+
+#[rustfmt::skip]
+const KING_EG: [i32; 64] = [
+    -50, -40, -30, -20, -20, -30, -40, -50,
+    -30, -20, -10,   0,   0, -10, -20, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  30,  40,  40,  30, -10, -30,
+    -30, -10,  20,  30,  30,  20, -10, -30,
+    -30, -30,   0,   0,   0,   0, -30, -30,
+    -50, -30, -30, -30, -30, -30, -30, -50,
+];
+
+/// Middlegame and endgame piece-square tables indexed by `Piece`
+const PSTS: [(&[i32; 64], &[i32; 64]); 6] = [
+    (&PAWN_MG, &PAWN_EG),
+    (&KNIGHT_PST, &KNIGHT_PST),
+    (&BISHOP_PST, &BISHOP_PST),
+    (&ROOK_PST, &ROOK_PST),
+    (&QUEEN_PST, &QUEEN_PST),
+    (&KING_MG, &KING_EG),
+];
+
+/// Score per square a piece can move to, indexed by `Piece`, and the number
+/// of squares a piece typically has, which scores zero
+const MOBILITY_WEIGHTS: [Score; 6] = [s(0, 0), s(4, 4), s(5, 5), s(2, 4), s(1, 2), s(0, 0)];
+const MOBILITY_BASELINE: [i32; 6] = [0, 4, 6, 7, 13, 0];
+
+const BISHOP_PAIR: Score = s(30, 50);
+const DOUBLED_PAWN: Score = s(-10, -20);
+const ISOLATED_PAWN: Score = s(-10, -15);
+/// Passed pawn bonus indexed by rank from the pawn's own side
+const PASSED_PAWN: [Score; 8] = [
+    s(0, 0),
+    s(5, 10),
+    s(10, 15),
+    s(15, 25),
+    s(30, 45),
+    s(50, 80),
+    s(80, 130),
+    s(0, 0),
+];
+
+/// Bonus for each pawn sheltering the king, up to three
+const PAWN_SHIELD: Score = s(12, 0);
+/// Penalty for each file on or next to the king with none of our pawns on it
+const OPEN_FILE_NEAR_KING: Score = s(-15, 0);
+/// Penalty per square around the king attacked by an enemy piece, indexed by `Piece`
+const KING_ZONE_ATTACK: [Score; 6] = [s(0, 0), s(-8, 0), s(-8, 0), s(-10, 0), s(-15, 0), s(0, 0)];
+
+/// Returns the static evaluation of `board` in centipawns from the point
+/// of view of the side to move, so that a positive score is good for
+/// whoever's turn it is
+pub fn evaluate(board: &Board) -> i32 {
+    let mut phase = 0;
+    let mut scores = [Score::default(); 2];
+
+    for color in [White, Black] {
+        let score = &mut scores[color as usize];
+        for piece in [Pawn, Knight, Bishop, Rook, Queen, King] {
+            let piece_bb = board.piece_bb(Some(color), piece);
+            phase += PHASE_WEIGHTS[piece as usize] * piece_bb.pop_count() as i32;
+            for sq in piece_bb {
+                *score += PIECE_VALUES[piece as usize] + pst(piece, color, sq);
+            }
+        }
+        if board.piece_bb(Some(color), Bishop).pop_count() >= 2 {
+            *score += BISHOP_PAIR;
+        }
+        *score += mobility(board, color);
+        *score += pawn_structure(board, color);
+        *score += king_safety(board, color);
+    }
+
+    let total = scores[White as usize] - scores[Black as usize];
+    let phase = phase.min(MAX_PHASE);
+    let eval = (total.mg * phase + total.eg * (MAX_PHASE - phase)) / MAX_PHASE;
+    match board.side_to_move() {
+        White => eval,
+        Black => -eval,
+    }
+}
+
+/// Returns the piece-square table score of `piece` of color `color` on `sq`
+fn pst(piece: Piece, color: Color, sq: Square) -> Score {
+    let i = match color {
+        White => sq as usize ^ 56,
+        Black => sq as usize,
+    };
+    let (mg, eg) = PSTS[piece as usize];
+    s(mg[i], eg[i])
+}
+
+/// Scores the number of squares each knight, bishop, rook and queen of color
+/// `color` can move to, not counting squares attacked by enemy pawns
+fn mobility(board: &Board, color: Color) -> Score {
+    let safe = !board.color_bb(color) & !board.pawn_attack_squares(!color);
+    let mut score = Score::default();
+    for piece in [Knight, Bishop, Rook, Queen] {
+        for sq in board.piece_bb(Some(color), piece) {
+            let attacks = match piece {
+                Knight => Board::knight_attacks(sq),
+                Bishop => board.bishop_attacks(sq, None),
+                Rook => board.rook_attacks(sq, None),
+                _ => board.queen_attacks(sq, None),
+            };
+            let count = (attacks & safe).pop_count() as i32;
+            score += MOBILITY_WEIGHTS[piece as usize] * (count - MOBILITY_BASELINE[piece as usize]);
+        }
+    }
+    score
+}
+
+/// Returns a bitboard of the squares on the files next to `file`
+fn adjacent_files(file: u8) -> Bitboard {
+    let file_bb = bitboard::A_FILE << file as i32;
+    Bitboard::east_one(file_bb) | Bitboard::west_one(file_bb)
+}
+
+/// Returns a bitboard of the squares on ranks in front of `sq` from
+/// the point of view of color `color`
+fn ranks_in_front(sq: Square, color: Color) -> Bitboard {
+    match color {
+        White if sq.rank() < 7 => Bitboard(!0) << (8 * (sq.rank() as i32 + 1)),
+        Black if sq.rank() > 0 => Bitboard(!0) >> (8 * (8 - sq.rank() as i32)),
+        _ => Bitboard(0),
+    }
+}
+
+/// Scores doubled, isolated and passed pawns of color `color`
+fn pawn_structure(board: &Board, color: Color) -> Score {
+    let pawns = board.piece_bb(Some(color), Pawn);
+    let op_pawns = board.piece_bb(Some(!color), Pawn);
+    let mut score = Score::default();
+
+    for file in 0..8 {
+        let on_file = (pawns & (bitboard::A_FILE << file)).pop_count() as i32;
+        if on_file > 1 {
+            score += DOUBLED_PAWN * (on_file - 1);
+        }
+    }
+
+    for sq in pawns {
+        let file_bb = bitboard::A_FILE << sq.file() as i32;
+        let adjacent = adjacent_files(sq.file());
+        if (pawns & adjacent).empty() {
+            score += ISOLATED_PAWN;
+        }
+        // No enemy pawn in front of it, on its file or the files next to it
+        if (op_pawns & (file_bb | adjacent) & ranks_in_front(sq, color)).empty() {
+            let rank = match color {
+                White => sq.rank(),
+                Black => 7 - sq.rank(),
+            };
+            score += PASSED_PAWN[rank as usize];
+        }
+    }
+    score
+}
+
+/// Scores the pawn shield in front of the king of color `color`, the open
+/// files around it and the enemy pieces attacking the squares around it
+fn king_safety(board: &Board, color: Color) -> Score {
+    let Some(king_square) = board.piece_bb(Some(color), King).bit_scan() else {
+        return Score::default();
+    };
+    let pawns = board.piece_bb(Some(color), Pawn);
+    let zone = Board::king_attacks(king_square) | king_square.as_bitboard();
+    let mut score = Score::default();
+
+    // Pawns on the two ranks in front of the king and the files next to it
+    let files = bitboard::A_FILE << king_square.file() as i32;
+    let files = files | adjacent_files(king_square.file());
+    let in_front = ranks_in_front(king_square, color);
+    let two_ranks = match color {
+        White => in_front & !(in_front << 16),
+        Black => in_front & !(in_front >> 16),
+    };
+    let shield = (pawns & files & two_ranks).pop_count().min(3) as i32;
+    score += PAWN_SHIELD * shield;
+
+    for sq in files.filter(|sq| sq.rank() == 0) {
+        if (pawns & (bitboard::A_FILE << sq.file() as i32)).empty() {
+            score += OPEN_FILE_NEAR_KING;
+        }
+    }
+
+    for piece in [Knight, Bishop, Rook, Queen] {
+        for sq in board.piece_bb(Some(!color), piece) {
+            let attacks = match piece {
+                Knight => Board::knight_attacks(sq),
+                Bishop => board.bishop_attacks(sq, None),
+                Rook => board.rook_attacks(sq, None),
+                _ => board.queen_attacks(sq, None),
+            };
+            let count = (attacks & zone).pop_count() as i32;
+            score += KING_ZONE_ATTACK[piece as usize] * count;
+        }
+    }
+    score
+}
+
+/// The score of being checkmated at the root. Being mated `n` plies from
+/// the root scores `-MATE_SCORE + n`, so that quicker mates score higher.
+pub const MATE_SCORE: i32 = 30_000;
+/// The deepest the search will go, in plies from the root
+pub const MAX_PLY: u32 = 64;
+
+/// How long a search may run. Searching stops as soon as any of the
+/// limits set is reached, and goes on to `MAX_PLY` if none are.
+///
+/// # Fields
+///
+/// * `depth` - the deepest iteration to search
+/// * `nodes` - the most positions to visit
+/// * `movetime` - the exact time to search for
+/// * `wtime`, `btime` - the time left on white's and black's clocks, from
+///   which the time to search for is worked out
+/// * `winc`, `binc` - the time added to white's and black's clocks per move
+/// * `movestogo` - the number of moves until the next time control
+/// * `stop` - stops the search when set, e.g. from another thread
+#[derive(Clone, Debug, Default)]
+pub struct SearchLimits {
+    pub depth: Option<u32>,
+    pub nodes: Option<u64>,
+    pub movetime: Option<Duration>,
+    pub wtime: Option<Duration>,
+    pub btime: Option<Duration>,
+    pub winc: Option<Duration>,
+    pub binc: Option<Duration>,
+    pub movestogo: Option<u32>,
+    pub stop: Arc<AtomicBool>,
+}
+
+/// The outcome of a search
+///
+/// # Fields
+///
+/// * `best_move` - the move to play, or None if there are no legal moves
+/// * `score` - the score of the best move in centipawns from the point of
+///   view of the side to move
+/// * `depth` - the depth of the last completed iteration
+/// * `pv` - the principal variation, the line of best play expected from
+///   both sides, starting with `best_move`
+/// * `nodes` - the number of positions visited
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchResult {
+    pub best_move: Option<CMove>,
+    pub score: i32,
+    pub depth: u32,
+    pub pv: Vec<CMove>,
+    pub nodes: u64,
+}
+
+/// Returns whether `score` means a forced mate for either side
+pub fn is_mate_score(score: i32) -> bool {
+    score.abs() >= MATE_SCORE - MAX_PLY as i32
+}
+
+/// Returns the number of moves until mate if `score` is a mate score,
+/// negative if the side to move is getting mated. Mate scores count plies,
+/// so a mate the side to move delivers on its nth move is 2n - 1 plies away.
+pub fn mate_in(score: i32) -> Option<i32> {
+    match is_mate_score(score) {
+        true if score > 0 => Some((MATE_SCORE - score + 1) / 2),
+        true => Some(-(MATE_SCORE + score) / 2),
+        false => None,
+    }
+}
+
+/// Mate scores count plies from the root, but the transposition table can
+/// give them back at a different ply, so they are stored counting plies
+/// from the position itself
+fn score_to_tt(score: i32, ply: u32) -> i32 {
+    match score {
+        s if is_mate_score(s) && s > 0 => s + ply as i32,
+        s if is_mate_score(s) => s - ply as i32,
+        s => s,
+    }
+}
+
+fn score_from_tt(score: i32, ply: u32) -> i32 {
+    match score {
+        s if is_mate_score(s) && s > 0 => s - ply as i32,
+        s if is_mate_score(s) => s + ply as i32,
+        s => s,
+    }
+}
+
+/// Searches `board` for the best move for the side to move by iterative
+/// deepening, until one of `limits` is reached. `board` is left in the
+/// same position it was given in.
+pub fn search(board: &mut Board, limits: &SearchLimits) -> SearchResult {
+    search_with_table(
+        board,
+        limits,
+        &TranspositionTable::default(),
+        1,
+        &mut |_| {},
+    )
+}
+
+/// Like `search`, but reuses the results stored in `tt` by earlier searches
+/// and stores its own there for later ones, as when playing a game. `report`
+/// is called with the result of each iteration as it completes.
+///
+/// With more than one thread, helper threads search the same position
+/// alongside the main thread, sharing only `tt`. The results they store make
+/// the main thread's search faster, and the result is the main thread's.
+/// `limits.nodes` applies to each thread on its own.
+pub fn search_with_table(
+    board: &mut Board,
+    limits: &SearchLimits,
+    tt: &TranspositionTable,
+    threads: usize,
+    report: &mut dyn FnMut(&SearchResult),
+) -> SearchResult {
+    tt.new_search();
+    let max_depth = limits.depth.unwrap_or(MAX_PLY).clamp(1, MAX_PLY);
+    // Set once the main thread is done, to stop the helpers
+    let done = AtomicBool::new(false);
+
+    thread::scope(|s| {
+        let helpers: Vec<_> = (1..threads)
+            .map(|id| {
+                let mut board = board.clone();
+                let done = &done;
+                s.spawn(move || {
+                    let mut helper = Searcher::new(&mut board, limits, tt, done, None);
+                    // Half the helpers search a ply ahead of the main thread,
+                    // so that the threads don't all search the same tree
+                    for depth in (1 + id as u32 % 2)..=max_depth {
+                        let mut pv = vec![];
+                        helper.negamax(depth, 0, -MATE_SCORE, MATE_SCORE, &mut pv);
+                        if helper.stopped {
+                            break;
+                        }
+                    }
+                    helper.nodes
+                })
+            })
+            .collect();
+
+        let time = TimeManager::new(limits, board.side_to_move());
+        let mut main = Searcher::new(board, limits, tt, &done, time);
+        let mut result = main.iterative_deepening(max_depth, report);
+        done.store(true, Ordering::Relaxed);
+        for helper in helpers {
+            result.nodes += helper.join().unwrap();
+        }
+        result
+    })
+}
+
+/// The state of a search in progress
+struct Searcher<'a> {
+    board: &'a mut Board,
+    limits: &'a SearchLimits,
+    tt: &'a TranspositionTable,
+    /// Set when the search has to stop on top of `limits.stop`, e.g. for
+    /// helper threads once the main thread is done
+    abort: &'a AtomicBool,
+    history: History,
+    killers: Killers,
+    time: Option<TimeManager>,
+    nodes: u64,
+    stopped: bool,
+}
+
+impl<'a> Searcher<'a> {
+    fn new(
+        board: &'a mut Board,
+        limits: &'a SearchLimits,
+        tt: &'a TranspositionTable,
+        abort: &'a AtomicBool,
+        time: Option<TimeManager>,
+    ) -> Searcher<'a> {
+        Searcher {
+            board,
+            limits,
+            tt,
+            abort,
+            history: History::default(),
+            killers: Killers::default(),
+            time,
+            nodes: 0,
+            stopped: false,
+        }
+    }
+
+    /// Searches one ply deeper at a time up to `max_depth`, until the search
+    /// runs out of time, and returns the result of the last iteration that
+    /// completed
+    fn iterative_deepening(
+        &mut self,
+        max_depth: u32,
+        report: &mut dyn FnMut(&SearchResult),
+    ) -> SearchResult {
+        let mut result = SearchResult {
+            best_move: None,
+            score: 0,
+            depth: 0,
+            pv: vec![],
+            nodes: 0,
+        };
+
+        let mut stable_iterations = 0;
+        for depth in 1..=max_depth {
+            let mut pv = vec![];
+            let score = self.negamax(depth, 0, -MATE_SCORE, MATE_SCORE, &mut pv);
+            // An iteration cut short can't be trusted, unless there is nothing
+            // better to fall back on
+            if self.stopped && result.best_move.is_some() {
+                break;
+            }
+            // The first iteration has no score to have dropped from
+            let score_drop = match result.depth {
+                0 => 0,
+                _ => result.score - score,
+            };
+            match result.best_move == pv.first().copied() {
+                true => stable_iterations += 1,
+                false => stable_iterations = 0,
+            }
+            result = SearchResult {
+                best_move: pv.first().copied(),
+                score,
+                depth,
+                pv,
+                nodes: self.nodes,
+            };
+            report(&result);
+            // No moves to search, or a forced mate was found
+            if result.best_move.is_none() || is_mate_score(score) || self.stopped {
+                break;
+            }
+            let out_of_time = self
+                .time
+                .as_ref()
+                .is_some_and(|time| !time.should_continue(stable_iterations, score_drop));
+            if out_of_time {
+                break;
+            }
+        }
+        result.nodes = self.nodes;
+        result
+    }
+
+    /// Returns whether one of the search limits has been reached. The clock
+    /// and the stop flag are only checked every so often since reading them
+    /// isn't free.
+    fn should_stop(&mut self) -> bool {
+        if self.stopped {
+            return true;
+        }
+        if self.limits.nodes.is_some_and(|nodes| self.nodes >= nodes) {
+            self.stopped = true;
+        }
+        if self.nodes.is_multiple_of(1024) {
+            let out_of_time = self.time.as_ref().is_some_and(|t| t.hard_limit_reached());
+            let stop =
+                self.limits.stop.load(Ordering::Relaxed) || self.abort.load(Ordering::Relaxed);
+            if out_of_time || stop {
+                self.stopped = true;
+            }
+        }
+        self.stopped
+    }
+
+    /// Returns the score of the position `depth` plies deep, bounded by
+    /// `alpha` and `beta` (fail hard), and fills `pv` with the best line
+    /// found from here. `ply` is the distance from the root, used to prefer
+    /// quicker mates.
+    fn negamax(
+        &mut self,
+        depth: u32,
+        ply: u32,
+        mut alpha: i32,
+        beta: i32,
+        pv: &mut Vec<CMove>,
+    ) -> i32 {
+        pv.clear();
+        if depth == 0 {
+            return self.quiescence(ply, alpha, beta);
+        }
+        self.nodes += 1;
+        if ply > 0 && self.should_stop() {
+            return 0;
+        }
+        // Repeating a position once is enough to count it a draw, since
+        // whatever was best the first time is best again
+        if ply > 0 && self.board.repetitions() > 0 {
+            return 0;
+        }
+        // The fifty-move rule doesn't draw a position that is already mate
+        if ply > 0 && self.board.fifty_move_rule_counter() >= 100 {
+            let mated = self.board.in_check() && self.board.generate_moves().is_empty();
+            return match mated {
+                true => -MATE_SCORE + ply as i32,
+                false => 0,
+            };
+        }
+
+        let key = self.board.hash();
+        let entry = self.tt.probe(key);
+        if let Some(entry) = entry.filter(|e| ply > 0 && e.depth() >= depth) {
+            let score = score_from_tt(entry.score(), ply);
+            match entry.bound() {
+                Bound::Exact => return score.clamp(alpha, beta),
+                Bound::Lower if score >= beta => return beta,
+                Bound::Upper if score <= alpha => return alpha,
+                _ => {}
+            }
+        }
+
+        let moves = self.board.generate_moves();
+        if moves.is_empty() {
+            return match self.board.in_check() {
+                true => -MATE_SCORE + ply as i32,
+                false => 0,
+            };
+        }
+        if ply >= MAX_PLY {
+            return evaluate(self.board);
+        }
+
+        let picker = MovePicker::new(
+            self.board,
+            moves,
+            entry.and_then(|e| e.best_move()),
+            self.killers.get(ply),
+            &self.history,
+        );
+
+        let mut child_pv = vec![];
+        let mut bound = Bound::Upper;
+        for m in picker {
+            self.board.make_move_mut(&m);
+            let score = -self.negamax(depth - 1, ply + 1, -beta, -alpha, &mut child_pv);
+            self.board.unmake_move();
+
+            // The score of a cut short search is meaningless, but the root
+            // always needs a move to fall back on
+            if self.stopped && (ply > 0 || !pv.is_empty()) {
+                return alpha;
+            }
+            if score >= beta {
+                if !m.is_capture() && m.is_promo().is_none() {
+                    self.killers.store(ply, m);
+                    self.history.update(self.board.side_to_move(), m, depth);
+                }
+                let score = score_to_tt(beta, ply);
+                self.tt.store(key, Some(m), score, depth, Bound::Lower);
+                return beta;
+            }
+            if score > alpha {
+                alpha = score;
+                bound = Bound::Exact;
+                pv.clear();
+                pv.push(m);
+                pv.append(&mut child_pv);
+            }
+        }
+
+        if !self.stopped {
+            let score = score_to_tt(alpha, ply);
+            self.tt.store(key, pv.first().copied(), score, depth, bound);
+        }
+        alpha
     }
 
-    for m in moves {
-        board.make_move_mut(m);
-        let eval = -alpha_beta(-beta, -alpha, depth - 1, moves, board);
-        // unmake_move(); TODO: implement
-        if eval >= beta {
-            return beta; // fail hard
+    /// Returns the score of the position once it is quiet, by searching only
+    /// captures and promotions until there are none worth making. The side to
+    /// move can always stand pat on the static evaluation instead, unless it
+    /// is in check, in which case every evasion is searched.
+    fn quiescence(&mut self, ply: u32, mut alpha: i32, beta: i32) -> i32 {
+        self.nodes += 1;
+        if self.should_stop() {
+            return 0;
         }
-        if eval > alpha {
-            alpha = eval;
+
+        let in_check = self.board.in_check();
+        let moves = self.board.generate_moves();
+        if moves.is_empty() {
+            return match in_check {
+                true => -MATE_SCORE + ply as i32,
+                false => 0,
+            };
+        }
+        if ply >= MAX_PLY {
+            return evaluate(self.board);
+        }
+
+        if !in_check {
+            let stand_pat = evaluate(self.board);
+            if stand_pat >= beta {
+                return beta;
+            }
+            alpha = alpha.max(stand_pat);
+        }
+
+        // Captures that lose material can't do better than standing pat
+        let moves: Vec<CMove> = match in_check {
+            true => moves,
+            false => moves
+                .into_iter()
+                .filter(|&m| m.is_promo().is_some() || m.is_capture() && self.board.see(m) >= 0)
+                .collect(),
+        };
+        let picker = MovePicker::new(self.board, moves, None, [None; 2], &self.history);
+
+        for m in picker {
+            self.board.make_move_mut(&m);
+            let score = -self.quiescence(ply + 1, -beta, -alpha);
+            self.board.unmake_move();
+
+            if self.stopped {
+                return alpha;
+            }
+            if score >= beta {
+                return beta;
+            }
+            alpha = alpha.max(score);
         }
+        alpha
     }
-    alpha
 }
@@ -0,0 +1,262 @@
+//! Magic bitboard lookup tables for rook and bishop attacks. The squares
+//! that can block a slider on a given square are masked out of the occupancy,
+//! multiplied by a magic number and shifted down to give an index into a table
+//! of precomputed attack sets. The magic numbers were found by trial and error
+//! so that no two occupancies with different attack sets share an index.
+//!
+//! The technique is described in full at:
+//! <https://www.chessprogramming.org/Magic_Bitboards>
+use super::bitboard::Bitboard;
+use super::tables;
+use super::utils::{Dir, Dir::*, Square};
+use num::FromPrimitive;
+use std::sync::LazyLock;
+
+/// Magic numbers for rook attacks, one per square
+pub const ROOK_MAGICS: [u64; 64] = [
+    0x2080002080400010,
+    0x00c0002001401000,
+    0x2100110008402002,
+    0x0880080081041000,
+    0x0200020020041008,
+    0x2300040008010012,
+    0x0c00283004008201,
+    0x0180010000407a80,
+    0x0168800080400020,
+    0x0010400040201000,
+    0x1001002001001048,
+    0x1001002408100100,
+    0x0801000408010012,
+    0x4001000209000400,
+    0x08a20004c8020001,
+    0x2002801145002280,
+    0x0080860021004200,
+    0x001000c009402002,
+    0x00b0002004002800,
+    0x100a808010020800,
+    0x8101010008000410,
+    0x0244008002000480,
+    0x0000040010810208,
+    0x2000020000448534,
+    0x4104400480008033,
+    0x0000810100204000,
+    0x0440430900200010,
+    0x4600240900100100,
+    0x0060080080040080,
+    0x0001000300080400,
+    0x0004084400011002,
+    0x0023040200008041,
+    0x0580050043002080,
+    0x0400804002802008,
+    0x0001002001004010,
+    0x1000200901001000,
+    0x4410800801800c00,
+    0xa012003806001004,
+    0x0020100104008802,
+    0x0004808402000041,
+    0x0010400170898000,
+    0x0080500020004004,
+    0x1040408012020020,
+    0x8010040008004040,
+    0x2001080100110004,
+    0x0000020004008080,
+    0x0021010810040002,
+    0x0800008c43020024,
+    0x0000800021005100,
+    0x0070201040008080,
+    0x0000d04282006a00,
+    0x0010014400080240,
+    0x0001080110050100,
+    0x0012000810240600,
+    0x0402000801040200,
+    0x028100108a004100,
+    0x0050800300102045,
+    0x8208210040120882,
+    0x8010600101183441,
+    0x020b000910006045,
+    0x0241001002480005,
+    0x0081000400880241,
+    0x0000009008024124,
+    0x0048122980410402,
+];
+
+/// Magic numbers for bishop attacks, one per square
+pub const BISHOP_MAGICS: [u64; 64] = [
+    0x0848020822040013,
+    0x8010a40085821200,
+    0x0008008430840822,
+    0x0808048108040000,
+    0x1304042100008104,
+    0x5001012010204023,
+    0x81048801b8200420,
+    0x200a008084012000,
+    0x0040102001042084,
+    0x840a505042428020,
+    0x0000700102202920,
+    0x44101c0c10800002,
+    0x0040040422000000,
+    0x0180020802090202,
+    0x4020020811041202,
+    0x000104308c042000,
+    0x4140661002424400,
+    0x0028012008010460,
+    0x0188062102002a00,
+    0x0014004840102008,
+    0x0105000290400002,
+    0x8001022200410400,
+    0x104a041918013446,
+    0x008a000082008238,
+    0x04a0060008100430,
+    0x0008220008820801,
+    0x2508041208005010,
+    0x4008080200202020,
+    0x2441001013004000,
+    0x0030008060407000,
+    0x4008108000420800,
+    0x0012021050290100,
+    0x0210080482200500,
+    0xcc01112048100480,
+    0x0020402806500440,
+    0x00048e0080580080,
+    0x0040102020020080,
+    0x0028010440080807,
+    0x4601041108008800,
+    0x8040810e04104200,
+    0x901210110400088a,
+    0xa003080212081050,
+    0x00c1004048401004,
+    0x900000a014400800,
+    0x0008021040405401,
+    0x4020008206002090,
+    0x0004190424030100,
+    0x0424008a02026250,
+    0x8004088250900040,
+    0x1c00430088a04200,
+    0x0001020094040001,
+    0x8040210020880061,
+    0x2010040450442032,
+    0x0800840850044001,
+    0x0004040802140004,
+    0x0004080a04222020,
+    0x8088802110022000,
+    0x1081a10416114400,
+    0x0205010a24060820,
+    0x0000000720411080,
+    0x1008000208430400,
+    0x580c026028810840,
+    0x802020441020a110,
+    0x12c0022401020018,
+];
+
+const ROOK_DIRS: [Dir; 4] = [Nort, East, Sout, West];
+const BISHOP_DIRS: [Dir; 4] = [Noea, Soea, Sowe, Nowe];
+
+static ROOK_TABLE: LazyLock<MagicTable> =
+    LazyLock::new(|| MagicTable::new(&ROOK_MAGICS, &ROOK_DIRS));
+static BISHOP_TABLE: LazyLock<MagicTable> =
+    LazyLock::new(|| MagicTable::new(&BISHOP_MAGICS, &BISHOP_DIRS));
+
+/// Returns a bitboard marking rook attacks from square `s`
+/// given the occupied squares `occupied_bb`
+pub fn rook_attacks(s: Square, occupied_bb: Bitboard) -> Bitboard {
+    ROOK_TABLE.attacks(s, occupied_bb)
+}
+
+/// Returns a bitboard marking bishop attacks from square `s`
+/// given the occupied squares `occupied_bb`
+pub fn bishop_attacks(s: Square, occupied_bb: Bitboard) -> Bitboard {
+    BISHOP_TABLE.attacks(s, occupied_bb)
+}
+
+/// Returns a bitboard marking ray attacks in direction `d` from
+/// square `s`. Ray attacks flow in direction `d`, but stop when
+/// a piece blocks the ray. The attack set includes the stopping piece.
+pub fn ray_attacks(d: Dir, s: Square, occupied_bb: Bitboard) -> Bitboard {
+    let mut attacks = tables::RAY_ATTACKS[d as usize][s as usize];
+    let blocking = attacks & occupied_bb;
+    let blocker = if d.pos() {
+        blocking.bit_scan()
+    } else {
+        blocking.bit_scan_reverse()
+    };
+    if let Some(blocker) = blocker {
+        attacks ^= tables::RAY_ATTACKS[d as usize][blocker as usize];
+    }
+    attacks
+}
+
+/// The magic lookup for a single square
+struct Magic {
+    /// The squares whose occupancy affects the attack set. Pieces on
+    /// the edge of the board can't block anything, so edges are left out.
+    mask: Bitboard,
+    magic: u64,
+    /// 64 minus the number of squares in `mask`
+    shift: u32,
+    /// Where this square's attack sets start in the shared table
+    offset: usize,
+}
+
+/// Attack sets for one slider type, built by scanning rays for every
+/// occupancy of every square's mask
+struct MagicTable {
+    magics: Vec<Magic>,
+    attacks: Vec<Bitboard>,
+}
+
+impl MagicTable {
+    fn new(magic_numbers: &[u64; 64], dirs: &[Dir; 4]) -> MagicTable {
+        let mut magics = Vec::with_capacity(64);
+        let mut attacks = vec![];
+
+        for (i, &magic) in magic_numbers.iter().enumerate() {
+            let s: Square = FromPrimitive::from_usize(i).unwrap();
+            // A ray without its last square, which is on the edge
+            let mask = dirs.iter().fold(Bitboard(0), |mask, &d| {
+                let ray = tables::RAY_ATTACKS[d as usize][i];
+                let edge = if d.pos() {
+                    ray.bit_scan_reverse()
+                } else {
+                    ray.bit_scan()
+                };
+                mask | edge.map_or(ray, |edge| ray ^ edge.as_bitboard())
+            });
+            let bits = mask.0.count_ones();
+            let entry = Magic {
+                mask,
+                magic,
+                shift: 64 - bits,
+                offset: attacks.len(),
+            };
+            attacks.resize(attacks.len() + (1 << bits), Bitboard(0));
+
+            // Walk every subset of the mask with the Carry-Rippler trick
+            let mut occupied_bb = Bitboard(0);
+            loop {
+                attacks[entry.index(occupied_bb)] = dirs
+                    .iter()
+                    .fold(Bitboard(0), |a, &d| a | ray_attacks(d, s, occupied_bb));
+                occupied_bb = Bitboard(occupied_bb.0.wrapping_sub(mask.0)) & mask;
+                if occupied_bb.empty() {
+                    break;
+                }
+            }
+            magics.push(entry);
+        }
+
+        MagicTable { magics, attacks }
+    }
+
+    fn attacks(&self, s: Square, occupied_bb: Bitboard) -> Bitboard {
+        self.attacks[self.magics[s as usize].index(occupied_bb)]
+    }
+}
+
+impl Magic {
+    /// Returns the index into the shared table of the attack set
+    /// for occupancy `occupied_bb`
+    fn index(&self, occupied_bb: Bitboard) -> usize {
+        let relevant = (occupied_bb & self.mask).0;
+        (relevant.wrapping_mul(self.magic) >> self.shift) as usize + self.offset
+    }
+}
@@ -1,6 +1,12 @@
-mod bitboard;
-mod board;
-mod cmove;
-mod engine;
-mod tables;
-mod utils;
+pub mod bitboard;
+pub mod board;
+pub mod cmove;
+pub mod engine;
+pub mod magic;
+pub mod movepick;
+pub mod pgn;
+pub(crate) mod tables;
+pub mod timeman;
+pub mod tt;
+pub mod utils;
+pub mod zobrist;
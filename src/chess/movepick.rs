@@ -0,0 +1,159 @@
+//! Move ordering for the search. Alpha-beta prunes the most when the best
+//! move is tried first, so moves are handed out in stages from the likeliest
+//! to be best to the least: the move the transposition table remembers,
+//! captures by most valuable victim and least valuable attacker, killer moves
+//! that caused cutoffs at the same ply elsewhere in the tree, and then the
+//! remaining quiet moves by how often they have caused cutoffs before.
+use super::board::Board;
+use super::cmove::CMove;
+use super::engine::MAX_PLY;
+use super::utils::{CPiece, Color, Piece};
+
+/// Piece values for ordering captures, indexed by `Piece`
+const ORDER_VALUES: [i32; 6] = [100, 300, 300, 500, 900, 10_000];
+
+/// History scores are halved once any reaches this, so that recent
+/// cutoffs count for more than old ones
+const MAX_HISTORY: i32 = 1 << 20;
+
+/// How often quiet moves caused a beta cutoff, weighted by depth, indexed by
+/// side to move, from square and to square
+pub struct History(Box<[[[i32; 64]; 64]; 2]>);
+
+impl Default for History {
+    fn default() -> Self {
+        History(Box::new([[[0; 64]; 64]; 2]))
+    }
+}
+
+impl History {
+    pub fn get(&self, c: Color, m: CMove) -> i32 {
+        self.0[c as usize][m.get_from() as usize][m.get_to() as usize]
+    }
+
+    /// Records that quiet move `m` by color `c` caused a cutoff `depth` plies
+    /// from the leaves
+    pub fn update(&mut self, c: Color, m: CMove, depth: u32) {
+        let score = &mut self.0[c as usize][m.get_from() as usize][m.get_to() as usize];
+        *score += (depth * depth) as i32;
+        if *score >= MAX_HISTORY {
+            self.0.iter_mut().flatten().flatten().for_each(|s| *s /= 2);
+        }
+    }
+}
+
+/// Two quiet moves per ply that caused a beta cutoff, most recent first
+pub struct Killers([[Option<CMove>; 2]; MAX_PLY as usize]);
+
+impl Default for Killers {
+    fn default() -> Self {
+        Killers([[None; 2]; MAX_PLY as usize])
+    }
+}
+
+impl Killers {
+    pub fn get(&self, ply: u32) -> [Option<CMove>; 2] {
+        self.0.get(ply as usize).copied().unwrap_or([None; 2])
+    }
+
+    /// Records that quiet move `m` caused a cutoff at `ply`
+    pub fn store(&mut self, ply: u32, m: CMove) {
+        if let Some(killers) = self.0.get_mut(ply as usize) {
+            if killers[0] != Some(m) {
+                killers[1] = killers[0];
+                killers[0] = Some(m);
+            }
+        }
+    }
+}
+
+/// The stages moves are handed out in, in order
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Stage {
+    HashMove,
+    Captures,
+    Killers,
+    Quiets,
+    Done,
+}
+
+/// An iterator over legal moves in a position, best first by the order
+/// described in the module documentation. Within a stage the best move is
+/// only looked for when it is asked for, so that the work of ordering the
+/// rest is saved when a move causes a cutoff.
+pub struct MovePicker {
+    moves: Vec<(CMove, Stage, i32)>,
+    stage: Stage,
+}
+
+impl MovePicker {
+    /// Creates a picker over `moves`, the legal moves in `board`
+    pub fn new(
+        board: &Board,
+        moves: Vec<CMove>,
+        hash_move: Option<CMove>,
+        killers: [Option<CMove>; 2],
+        history: &History,
+    ) -> MovePicker {
+        let c = board.side_to_move();
+        let moves = moves
+            .into_iter()
+            .map(|m| {
+                if Some(m) == hash_move {
+                    (m, Stage::HashMove, 0)
+                } else if m.is_capture() || m.is_promo().is_some() {
+                    (m, Stage::Captures, mvv_lva(board, m))
+                } else if let Some(i) = killers.iter().position(|&k| k == Some(m)) {
+                    // The most recent killer first
+                    (m, Stage::Killers, -(i as i32))
+                } else {
+                    (m, Stage::Quiets, history.get(c, m))
+                }
+            })
+            .collect();
+        MovePicker {
+            moves,
+            stage: Stage::HashMove,
+        }
+    }
+}
+
+/// Scores captures by the value of the piece taken, and then by the value of
+/// the piece taking it, with promotions counting the piece promoted to
+fn mvv_lva(board: &Board, m: CMove) -> i32 {
+    let value = |p: Option<Piece>| p.map_or(0, |p| ORDER_VALUES[p as usize]);
+    let victim = match m.is_ep_capture() {
+        true => Some(Piece::Pawn),
+        false => board.piece_on_square(m.get_to()).map(|CPiece(p, _)| p),
+    };
+    let attacker = board.piece_on_square(m.get_from()).map(|CPiece(p, _)| p);
+    10 * (value(victim) + value(m.is_promo())) - value(attacker)
+}
+
+impl Iterator for MovePicker {
+    type Item = CMove;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.stage != Stage::Done {
+            let best = self
+                .moves
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, stage, _))| *stage == self.stage)
+                .max_by_key(|(_, (_, _, score))| *score)
+                .map(|(i, _)| i);
+            match best {
+                Some(i) => return Some(self.moves.swap_remove(i).0),
+                None => {
+                    self.stage = match self.stage {
+                        Stage::HashMove => Stage::Captures,
+                        Stage::Captures => Stage::Killers,
+                        Stage::Killers => Stage::Quiets,
+                        Stage::Quiets | Stage::Done => Stage::Done,
+                    }
+                }
+            }
+        }
+        None
+    }
+}
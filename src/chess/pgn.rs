@@ -0,0 +1,565 @@
+//! Reading and writing games in Portable Game Notation. A PGN file holds any
+//! number of games, each made of tag pairs like `[White "Tal, Mikhail"]`
+//! followed by movetext: moves in SAN with move numbers, `{comments}`,
+//! `;` comments to the end of the line, numeric annotation glyphs like `$1`,
+//! `(variations)` which can nest, and a result.
+//!
+//! Games are replayed on `Board` as they are read, so every move is checked
+//! to be legal, and variations are replayed from the position before the
+//! move they replace.
+//!
+//! The format is specified at:
+//! <https://www.chessprogramming.org/Portable_Game_Notation>
+use super::board::{Board, CreateBoardError, ParseMoveError, Status};
+use super::cmove::CMove;
+use super::utils::Color;
+use std::fmt;
+
+/// The tags every game has, in the order they are written
+pub const SEVEN_TAG_ROSTER: [&str; 7] =
+    ["Event", "Site", "Date", "Round", "White", "Black", "Result"];
+
+/// Lines of exported movetext are kept under this many characters
+const MAX_LINE_LENGTH: usize = 80;
+
+/// The reasons PGN can fail to be read
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PgnError {
+    /// The text isn't valid PGN. `line` counts from 1
+    Syntax { line: usize, message: String },
+    /// The `FEN` tag doesn't describe a valid position
+    InvalidFen { line: usize, err: CreateBoardError },
+    /// A move couldn't be played. `ply` counts from 1 at the first move of
+    /// the game, whatever position it starts from
+    IllegalMove {
+        line: usize,
+        ply: u32,
+        san: String,
+        err: ParseMoveError,
+    },
+}
+
+impl fmt::Display for PgnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgnError::Syntax { line, message } => write!(f, "line {line}: {message}"),
+            PgnError::InvalidFen { line, err } => write!(f, "line {line}: {err}"),
+            PgnError::IllegalMove { line, ply, err, .. } => {
+                write!(f, "line {line}, ply {ply}: {err}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PgnError {}
+
+/// A move in a game, with its annotations and the variations played
+/// instead of it
+///
+/// # Fields
+///
+/// * `m` - the move
+/// * `san` - the move in SAN as it was read or would be written
+/// * `nags` - the numeric annotation glyphs after the move, e.g. 1 for `!`
+/// * `comments_before` - comments between the previous move and this one,
+///   only kept for the first move of a game or variation
+/// * `comments` - comments after the move
+/// * `variations` - lines played instead of this move
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PgnMove {
+    pub m: CMove,
+    pub san: String,
+    pub nags: Vec<u8>,
+    pub comments_before: Vec<String>,
+    pub comments: Vec<String>,
+    pub variations: Vec<Vec<PgnMove>>,
+}
+
+/// A game read from or to be written to PGN
+///
+/// # Fields
+///
+/// * `tags` - the tag pairs in the order they were read
+/// * `moves` - the main line
+/// * `result` - `1-0`, `0-1`, `1/2-1/2` or `*` for an unfinished game
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub tags: Vec<(String, String)>,
+    pub moves: Vec<PgnMove>,
+    pub result: String,
+}
+
+/// Reads every game in `pgn`, replaying each one to check that its moves
+/// are legal
+pub fn parse(pgn: &str) -> Result<Vec<Game>, PgnError> {
+    let mut parser = Parser {
+        tokens: tokenize(pgn)?,
+        pos: 0,
+    };
+    let mut games = vec![];
+    while parser.peek().is_some() {
+        games.push(parser.game()?);
+    }
+    Ok(games)
+}
+
+impl Game {
+    /// Returns the game played on `board`, from the position it was created
+    /// in, with the seven tag roster left unknown
+    pub fn from_board(board: &Board) -> Game {
+        let mut start = board.clone();
+        let mut played = vec![];
+        while let Some(m) = start.unmake_move() {
+            played.push(m);
+        }
+
+        let mut tags: Vec<(String, String)> = SEVEN_TAG_ROSTER
+            .iter()
+            .map(|&name| (name.to_string(), default_tag(name).to_string()))
+            .collect();
+        if start.to_fen() != Board::new().to_fen() {
+            tags.push(("SetUp".to_string(), "1".to_string()));
+            tags.push(("FEN".to_string(), start.to_fen()));
+        }
+
+        let mut moves = vec![];
+        for m in played.into_iter().rev() {
+            moves.push(PgnMove {
+                m,
+                san: start.to_san(m),
+                nags: vec![],
+                comments_before: vec![],
+                comments: vec![],
+                variations: vec![],
+            });
+            start.make_move_mut(&m);
+        }
+
+        let result = match start.status() {
+            Status::Checkmate if start.side_to_move() == Color::White => "0-1",
+            Status::Checkmate => "1-0",
+            Status::Ongoing => "*",
+            _ => "1/2-1/2",
+        };
+        let mut game = Game {
+            tags,
+            moves,
+            result: result.to_string(),
+        };
+        game.set_tag("Result", result);
+        game
+    }
+
+    /// Returns the value of tag `name`, if the game has it
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Sets tag `name` to `value`, adding it if the game doesn't have it
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(n, _)| n == name) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+
+    /// Returns the position the game starts from, given by the `FEN` tag or
+    /// the standard starting position if it has none
+    pub fn start_board(&self) -> Result<Board, CreateBoardError> {
+        match self.tag("FEN") {
+            Some(fen) => Board::from_fen(fen),
+            None => Ok(Board::new()),
+        }
+    }
+
+    /// Returns the position at the end of the main line
+    pub fn board(&self) -> Result<Board, CreateBoardError> {
+        let mut board = self.start_board()?;
+        for pgn_move in &self.moves {
+            board.make_move_mut(&pgn_move.m);
+        }
+        Ok(board)
+    }
+
+    /// Returns the game in PGN, with the seven tag roster first and the
+    /// movetext wrapped to lines of under 80 characters
+    pub fn to_pgn(&self) -> String {
+        let mut pgn = String::new();
+        for name in SEVEN_TAG_ROSTER {
+            let value = self.tag(name).unwrap_or(default_tag(name));
+            pgn.push_str(&format!("[{name} \"{}\"]\n", escape(value)));
+        }
+        for (name, value) in &self.tags {
+            if !SEVEN_TAG_ROSTER.contains(&name.as_str()) {
+                pgn.push_str(&format!("[{name} \"{}\"]\n", escape(value)));
+            }
+        }
+        pgn.push('\n');
+
+        let start_ply = self.start_board().map_or(0, |board| board.ply());
+        let mut words = vec![];
+        write_line(&self.moves, start_ply, &mut words);
+        words.push(self.result.clone());
+
+        let mut line = String::new();
+        for word in words {
+            if !line.is_empty() && line.len() + 1 + word.len() >= MAX_LINE_LENGTH {
+                pgn.push_str(&line);
+                pgn.push('\n');
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&word);
+        }
+        pgn.push_str(&line);
+        pgn.push('\n');
+        pgn
+    }
+}
+
+/// Returns the value a seven tag roster tag has when it isn't known
+fn default_tag(name: &str) -> &'static str {
+    match name {
+        "Date" => "????.??.??",
+        "Result" => "*",
+        _ => "?",
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Appends the words of the movetext of `moves`, the first of which is played
+/// at game ply `ply`, to `words`
+fn write_line(moves: &[PgnMove], mut ply: u32, words: &mut Vec<String>) {
+    // Black's moves only need a number at the start of a line or after
+    // something that isn't a move
+    let mut needs_number = true;
+    for pgn_move in moves {
+        for comment in &pgn_move.comments_before {
+            words.push(format!("{{{comment}}}"));
+            needs_number = true;
+        }
+        let number = ply / 2 + 1;
+        if ply.is_multiple_of(2) {
+            words.push(format!("{number}. {}", pgn_move.san));
+        } else if needs_number {
+            words.push(format!("{number}... {}", pgn_move.san));
+        } else {
+            words.push(pgn_move.san.clone());
+        }
+        needs_number = false;
+
+        for nag in &pgn_move.nags {
+            words.push(format!("${nag}"));
+        }
+        for comment in &pgn_move.comments {
+            words.push(format!("{{{comment}}}"));
+            needs_number = true;
+        }
+        for variation in &pgn_move.variations {
+            let mut variation_words = vec![];
+            write_line(variation, ply, &mut variation_words);
+            if let Some(first) = variation_words.first_mut() {
+                first.insert(0, '(');
+            }
+            if let Some(last) = variation_words.last_mut() {
+                last.push(')');
+            }
+            words.extend(variation_words);
+            needs_number = true;
+        }
+        ply += 1;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Tag(String, String),
+    Comment(String),
+    Nag(u8),
+    MoveNumber,
+    Move(String),
+    VariationStart,
+    VariationEnd,
+    Result(String),
+}
+
+/// Splits `pgn` into tokens, each with the line it starts on
+fn tokenize(pgn: &str) -> Result<Vec<(Token, usize)>, PgnError> {
+    let mut tokens = vec![];
+    let mut chars = pgn.chars().peekable();
+    let mut line = 1;
+    let syntax = |line, message: &str| PgnError::Syntax {
+        line,
+        message: message.to_string(),
+    };
+
+    // Whether the next character starts a line, for `%` escapes
+    let mut line_start = true;
+    while let Some(c) = chars.next() {
+        let start = line;
+        let at_line_start = line_start;
+        line_start = c == '\n';
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '%' if at_line_start => {
+                // An escaped line for other software to read
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                        line_start = true;
+                        break;
+                    }
+                }
+            }
+            ';' => {
+                let mut comment = String::new();
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                        line_start = true;
+                        break;
+                    }
+                    comment.push(c);
+                }
+                tokens.push((Token::Comment(comment.trim().to_string()), start));
+            }
+            '{' => {
+                let mut comment = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            comment.push(c);
+                        }
+                        None => return Err(syntax(start, "unterminated comment")),
+                    }
+                }
+                let comment = comment.split_whitespace().collect::<Vec<_>>().join(" ");
+                tokens.push((Token::Comment(comment), start));
+            }
+            '[' => {
+                let mut tag = String::new();
+                let mut in_string = false;
+                loop {
+                    match chars.next() {
+                        Some(']') if !in_string => break,
+                        Some('"') => {
+                            in_string = !in_string;
+                            tag.push('"');
+                        }
+                        Some('\\') if in_string => {
+                            tag.push('\\');
+                            tag.extend(chars.next());
+                        }
+                        Some('\n') => return Err(syntax(start, "unterminated tag")),
+                        Some(c) => tag.push(c),
+                        None => return Err(syntax(start, "unterminated tag")),
+                    }
+                }
+                tokens.push((
+                    parse_tag(&tag).ok_or_else(|| syntax(start, "invalid tag"))?,
+                    start,
+                ));
+            }
+            '(' => tokens.push((Token::VariationStart, start)),
+            ')' => tokens.push((Token::VariationEnd, start)),
+            '$' => {
+                let mut nag = String::new();
+                while let Some(&c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                    nag.push(c);
+                    chars.next();
+                }
+                let nag = nag.parse().map_err(|_| syntax(start, "invalid NAG"))?;
+                tokens.push((Token::Nag(nag), start));
+            }
+            _ => {
+                let mut word = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "{}()[];$".contains(c) {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push((word_token(&word), start));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Returns the tag in `tag`, the text between the brackets of a tag pair
+fn parse_tag(tag: &str) -> Option<Token> {
+    let (name, value) = tag.trim().split_once(char::is_whitespace)?;
+    let value = value.trim().strip_prefix('"')?.strip_suffix('"')?;
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    Some(Token::Tag(name.to_string(), unescaped))
+}
+
+/// Returns the token for a word of movetext. A move number can run into
+/// the move after it, as in `1.e4`.
+fn word_token(word: &str) -> Token {
+    match word {
+        "1-0" | "0-1" | "1/2-1/2" | "*" => return Token::Result(word.to_string()),
+        _ => {}
+    }
+    let rest = word.trim_start_matches(|c: char| c.is_ascii_digit());
+    // Digits not followed by dots are part of the move, as in `0-0`
+    let san = match rest.starts_with('.') || rest.is_empty() {
+        true => rest.trim_start_matches('.'),
+        false => word,
+    };
+    match san {
+        "" => Token::MoveNumber,
+        _ => Token::Move(san.to_string()),
+    }
+}
+
+/// Returns the NAG for a move suffix annotation like `!?`
+fn suffix_nag(suffix: &str) -> Option<u8> {
+    match suffix {
+        "!" => Some(1),
+        "?" => Some(2),
+        "!!" => Some(3),
+        "??" => Some(4),
+        "!?" => Some(5),
+        "?!" => Some(6),
+        _ => None,
+    }
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.pos)
+    }
+
+    fn syntax(&self, message: &str) -> PgnError {
+        let line = self
+            .tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map_or(1, |t| t.1);
+        PgnError::Syntax {
+            line,
+            message: message.to_string(),
+        }
+    }
+
+    /// Reads one game: its tags, its movetext and its result
+    fn game(&mut self) -> Result<Game, PgnError> {
+        let mut tags = vec![];
+        let mut fen_line = 1;
+        while let Some((Token::Tag(name, value), line)) = self.peek() {
+            if name == "FEN" {
+                fen_line = *line;
+            }
+            tags.push((name.clone(), value.clone()));
+            self.pos += 1;
+        }
+
+        let mut game = Game {
+            tags,
+            moves: vec![],
+            result: "*".to_string(),
+        };
+        let mut board = game.start_board().map_err(|err| PgnError::InvalidFen {
+            line: fen_line,
+            err,
+        })?;
+        let start_ply = board.ply();
+        game.moves = self.line(&mut board, start_ply)?;
+
+        match self.peek() {
+            Some((Token::Result(result), _)) => {
+                game.result = result.clone();
+                self.pos += 1;
+            }
+            // A game without a result ends at the next game's tags
+            Some((Token::Tag(..), _)) | None => {}
+            Some((Token::VariationEnd, _)) => return Err(self.syntax("unmatched ')'")),
+            Some(_) => return Err(self.syntax("unexpected token")),
+        }
+        Ok(game)
+    }
+
+    /// Reads a line of moves played on `board`, up to the end of a variation,
+    /// a result or the next game. `start_ply` is the ply of the game's first
+    /// move, so that plies in errors count from the start of the game.
+    fn line(&mut self, board: &mut Board, start_ply: u32) -> Result<Vec<PgnMove>, PgnError> {
+        let mut moves: Vec<PgnMove> = vec![];
+        let mut comments_before = vec![];
+        while let Some((token, line)) = self.peek().cloned() {
+            match token {
+                Token::MoveNumber => {}
+                Token::Move(text) => {
+                    let san = text.trim_end_matches(['!', '?']);
+                    let m = board.parse_san(san).map_err(|err| PgnError::IllegalMove {
+                        line,
+                        ply: board.ply() - start_ply + 1,
+                        san: text.clone(),
+                        err,
+                    })?;
+                    moves.push(PgnMove {
+                        m,
+                        san: board.to_san(m),
+                        nags: suffix_nag(&text[san.len()..]).into_iter().collect(),
+                        comments_before: std::mem::take(&mut comments_before),
+                        comments: vec![],
+                        variations: vec![],
+                    });
+                    board.make_move_mut(&m);
+                }
+                Token::Nag(nag) => match moves.last_mut() {
+                    Some(last) => last.nags.push(nag),
+                    None => return Err(self.syntax("NAG before any move")),
+                },
+                Token::Comment(comment) => match moves.last_mut() {
+                    Some(last) => last.comments.push(comment),
+                    None => comments_before.push(comment),
+                },
+                Token::VariationStart => {
+                    if moves.is_empty() {
+                        return Err(self.syntax("variation before any move"));
+                    }
+                    // The variation replaces the last move
+                    let mut before = board.clone();
+                    before.unmake_move();
+                    self.pos += 1;
+                    let variation = self.line(&mut before, start_ply)?;
+                    match self.peek() {
+                        Some((Token::VariationEnd, _)) => {}
+                        _ => return Err(self.syntax("unterminated variation")),
+                    }
+                    moves.last_mut().unwrap().variations.push(variation);
+                }
+                Token::VariationEnd | Token::Result(_) | Token::Tag(..) => break,
+            }
+            self.pos += 1;
+        }
+        Ok(moves)
+    }
+}
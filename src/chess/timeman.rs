@@ -0,0 +1,105 @@
+//! Deciding how long to search for when playing on a clock. A search gets a
+//! soft budget, past which no new iteration of iterative deepening is
+//! started, and a hard budget, past which the search is stopped outright.
+//! The soft budget shrinks while the best move stays the same from one
+//! iteration to the next, and grows when the score drops, since that is when
+//! searching deeper is most likely to change the move played.
+use super::engine::SearchLimits;
+use super::utils::Color;
+use std::time::{Duration, Instant};
+
+/// Time kept back from every move so that the engine doesn't lose on time
+/// to the overhead of talking to the GUI
+pub const MOVE_OVERHEAD: Duration = Duration::from_millis(30);
+
+/// How many more moves a game is assumed to last when the time control
+/// doesn't say
+const DEFAULT_MOVES_TO_GO: u32 = 25;
+
+/// The soft budget as a percentage, indexed by the number of iterations the
+/// best move has stayed the same for, up to the last entry
+const STABILITY_SCALE: [u32; 4] = [125, 100, 85, 70];
+
+/// The time budgets for one search
+///
+/// # Fields
+///
+/// * `start` - when the search started
+/// * `soft` - the time after which no new iteration is started
+/// * `hard` - the time after which the search is stopped
+/// * `fixed` - whether the search was given a fixed move time, which is used
+///   in full rather than scaled by how the search is going
+pub struct TimeManager {
+    start: Instant,
+    soft: Duration,
+    hard: Duration,
+    fixed: bool,
+}
+
+impl TimeManager {
+    /// Works out the budgets for a search by `side` under `limits`, with the
+    /// clock starting now. Returns None if `limits` doesn't limit time.
+    pub fn new(limits: &SearchLimits, side: Color) -> Option<TimeManager> {
+        let start = Instant::now();
+        if let Some(movetime) = limits.movetime {
+            return Some(TimeManager {
+                start,
+                soft: movetime,
+                hard: movetime,
+                fixed: true,
+            });
+        }
+
+        let (time, inc) = match side {
+            Color::White => (limits.wtime?, limits.winc.unwrap_or_default()),
+            Color::Black => (limits.btime?, limits.binc.unwrap_or_default()),
+        };
+        let moves_to_go = limits.movestogo.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+        let available = time.saturating_sub(MOVE_OVERHEAD);
+        let soft = time / moves_to_go + inc * 3 / 4;
+        let hard = (soft * 4).min(available);
+        Some(TimeManager {
+            start,
+            soft: soft.min(hard),
+            hard,
+            fixed: false,
+        })
+    }
+
+    /// Returns the time after which no new iteration is started, before
+    /// scaling by stability
+    pub fn soft(&self) -> Duration {
+        self.soft
+    }
+
+    /// Returns the time after which the search is stopped
+    pub fn hard(&self) -> Duration {
+        self.hard
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns whether the search has to stop now
+    pub fn hard_limit_reached(&self) -> bool {
+        self.elapsed() >= self.hard
+    }
+
+    /// Returns whether another iteration should be started, given how many
+    /// iterations in a row the best move has stayed the same for and how
+    /// much the score dropped in the last iteration, in centipawns
+    pub fn should_continue(&self, stable_iterations: usize, score_drop: i32) -> bool {
+        if self.fixed {
+            return self.elapsed() < self.hard;
+        }
+        let mut scale = STABILITY_SCALE[stable_iterations.min(STABILITY_SCALE.len() - 1)];
+        if score_drop > 75 {
+            scale *= 2;
+        } else if score_drop > 25 {
+            scale = scale * 3 / 2;
+        }
+        let soft = (self.soft * scale / 100).min(self.hard);
+        self.elapsed() < soft
+    }
+}
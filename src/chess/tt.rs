@@ -0,0 +1,244 @@
+//! A transposition table caching search results by Zobrist hash, so that a
+//! position reached again through a different move order, or searched again
+//! at the next iteration, doesn't have to be searched from scratch.
+//!
+//! The table is an array of buckets holding two entries each. The first
+//! entry is depth-preferred, only replaced by a search at least as deep or
+//! from a newer search, and the second is always replaced.
+//!
+//! The table is shared by every search thread without locking. Each entry
+//! is two atomic words, the data and the key XORed with the data, so that
+//! an entry torn by two threads writing it at once fails the key check on
+//! probing instead of handing back one position's data for another.
+//!
+//! The lock-less scheme is Hyatt and Mann's, described at:
+//! <https://www.chessprogramming.org/Shared_Hash_Table#Lock-less>
+use super::cmove::CMove;
+use std::mem;
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+
+/// The table size used when none is given, in MB
+pub const DEFAULT_SIZE_MB: usize = 16;
+
+/// How a stored score relates to the true score of the position
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bound {
+    /// The score is exact
+    Exact = 1,
+    /// The search failed high, the true score is at least the stored score
+    Lower = 2,
+    /// The search failed low, the true score is at most the stored score
+    Upper = 3,
+}
+
+/// A search result for one position
+///
+/// # Fields
+///
+/// * `key` - the full hash of the position, to tell apart positions that
+///   land in the same bucket
+/// * `best_move` - the best move found packed into 16 bits, or 0 if none
+/// * `score` - the score of the position
+/// * `depth` - the depth the position was searched to
+/// * `bound` - whether `score` is exact or a bound
+/// * `age` - the search the entry was stored by
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    key: u64,
+    best_move: u16,
+    score: i16,
+    depth: u8,
+    bound: Bound,
+    age: u8,
+}
+
+impl Entry {
+    /// Returns the best move found in the position, if any
+    pub fn best_move(&self) -> Option<CMove> {
+        match self.best_move {
+            0 => None,
+            bits => Some(CMove::from_bits(bits)),
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        self.score as i32
+    }
+
+    pub fn depth(&self) -> u32 {
+        self.depth as u32
+    }
+
+    pub fn bound(&self) -> Bound {
+        self.bound
+    }
+
+    /// Packs everything but the key into one word. The bound is never 0, so
+    /// neither is the word, which leaves 0 to mean an empty slot.
+    fn data(&self) -> u64 {
+        self.best_move as u64
+            | (self.score as u16 as u64) << 16
+            | (self.depth as u64) << 32
+            | (self.bound as u64) << 40
+            | (self.age as u64) << 48
+    }
+
+    fn from_data(key: u64, data: u64) -> Option<Entry> {
+        let bound = match (data >> 40) & 0xff {
+            1 => Bound::Exact,
+            2 => Bound::Lower,
+            3 => Bound::Upper,
+            _ => return None,
+        };
+        Some(Entry {
+            key,
+            best_move: data as u16,
+            score: (data >> 16) as u16 as i16,
+            depth: (data >> 32) as u8,
+            bound,
+            age: (data >> 48) as u8,
+        })
+    }
+}
+
+/// One slot of a bucket, holding the key XORed with the data and the data
+#[derive(Default)]
+struct Slot {
+    check: AtomicU64,
+    data: AtomicU64,
+}
+
+impl Slot {
+    /// Returns the entry in the slot if it is whole, regardless of its key
+    fn load(&self) -> Option<Entry> {
+        let data = self.data.load(Ordering::Relaxed);
+        let key = self.check.load(Ordering::Relaxed) ^ data;
+        Entry::from_data(key, data)
+    }
+
+    fn save(&self, entry: &Entry) {
+        let data = entry.data();
+        self.check.store(entry.key ^ data, Ordering::Relaxed);
+        self.data.store(data, Ordering::Relaxed);
+    }
+
+    fn clear(&self) {
+        self.check.store(0, Ordering::Relaxed);
+        self.data.store(0, Ordering::Relaxed);
+    }
+}
+
+/// A depth-preferred slot and an always-replace slot
+#[derive(Default)]
+struct Bucket([Slot; 2]);
+
+pub struct TranspositionTable {
+    buckets: Vec<Bucket>,
+    age: AtomicU8,
+}
+
+impl Default for TranspositionTable {
+    fn default() -> Self {
+        Self::new(DEFAULT_SIZE_MB)
+    }
+}
+
+impl TranspositionTable {
+    /// Creates an empty table taking up at most `size_mb` MB. The number of
+    /// buckets is rounded down to a power of two, and is at least one.
+    pub fn new(size_mb: usize) -> TranspositionTable {
+        let bytes = size_mb.saturating_mul(1024 * 1024);
+        let count = (bytes / mem::size_of::<Bucket>()).max(1);
+        let count = 1 << count.ilog2();
+        TranspositionTable {
+            buckets: (0..count).map(|_| Bucket::default()).collect(),
+            age: AtomicU8::new(0),
+        }
+    }
+
+    /// Resizes the table to take up at most `size_mb` MB, clearing it
+    pub fn resize(&mut self, size_mb: usize) {
+        *self = TranspositionTable::new(size_mb);
+    }
+
+    /// Empties the table, e.g. before a new game
+    pub fn clear(&self) {
+        self.buckets
+            .iter()
+            .flat_map(|Bucket(slots)| slots)
+            .for_each(Slot::clear);
+        self.age.store(0, Ordering::Relaxed);
+    }
+
+    /// Marks the start of a new search, so that entries from earlier
+    /// searches can be replaced regardless of their depth
+    pub fn new_search(&self) {
+        self.age.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn bucket(&self, key: u64) -> &Bucket {
+        // The number of buckets is a power of two
+        &self.buckets[key as usize & (self.buckets.len() - 1)]
+    }
+
+    /// Returns the entry for the position with hash `key`, if one is stored
+    pub fn probe(&self, key: u64) -> Option<Entry> {
+        let Bucket(slots) = self.bucket(key);
+        slots
+            .iter()
+            .filter_map(Slot::load)
+            .find(|entry| entry.key == key)
+    }
+
+    /// Stores a search result for the position with hash `key`. `score` must
+    /// fit in 16 bits. If `best_move` is None, the move already stored for
+    /// the position is kept.
+    pub fn store(&self, key: u64, best_move: Option<CMove>, score: i32, depth: u32, bound: Bound) {
+        let age = self.age.load(Ordering::Relaxed);
+        let Bucket(slots) = self.bucket(key);
+        let [preferred, always] = slots.each_ref().map(Slot::load);
+
+        let old_move = [preferred, always]
+            .into_iter()
+            .flatten()
+            .find(|e| e.key == key)
+            .map_or(0, |e| e.best_move);
+        let entry = Entry {
+            key,
+            best_move: best_move.map_or(old_move, |m| m.to_bits()),
+            score: score as i16,
+            depth: depth.min(u8::MAX as u32) as u8,
+            bound,
+            age,
+        };
+
+        match preferred {
+            Some(old) if old.key != key && old.age == age && entry.depth < old.depth => {
+                slots[1].save(&entry);
+            }
+            Some(old) => {
+                // The old depth-preferred entry still beats whatever is in
+                // the always-replace slot
+                if old.key != key {
+                    slots[1].save(&old);
+                }
+                slots[0].save(&entry);
+            }
+            None => slots[0].save(&entry),
+        }
+    }
+
+    /// Returns how full the table is in permille, estimated from the first
+    /// thousand buckets
+    pub fn hashfull(&self) -> u32 {
+        let age = self.age.load(Ordering::Relaxed);
+        let sample = &self.buckets[..self.buckets.len().min(1000)];
+        let used = sample
+            .iter()
+            .flat_map(|Bucket(slots)| slots)
+            .filter_map(Slot::load)
+            .filter(|e| e.age == age)
+            .count();
+        (used * 1000 / (sample.len() * 2)) as u32
+    }
+}
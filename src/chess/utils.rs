@@ -1,9 +1,10 @@
 use super::bitboard::Bitboard;
 use num::FromPrimitive;
+use std::fmt;
 use std::ops::Not;
 use Dir::*;
 
-#[derive(Clone, Copy, FromPrimitive, Debug)]
+#[derive(Clone, Copy, FromPrimitive, Debug, PartialEq, Eq)]
 pub enum Color {
     White,
     Black,
@@ -21,7 +22,7 @@ impl Not for Color {
 }
 
 /// All chess piece types
-#[derive(Clone, Copy, FromPrimitive, Debug)]
+#[derive(Clone, Copy, FromPrimitive, Debug, PartialEq, Eq)]
 pub enum Piece {
     Pawn,
     Knight,
@@ -30,8 +31,64 @@ pub enum Piece {
     Queen,
     King,
 }
+
+/// Formats the piece as its letter in algebraic notation, e.g. `N` for a knight
+impl fmt::Display for Piece {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Piece::Pawn => 'P',
+            Piece::Knight => 'N',
+            Piece::Bishop => 'B',
+            Piece::Rook => 'R',
+            Piece::Queen => 'Q',
+            Piece::King => 'K',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// A piece of a particular color
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct CPiece(pub Piece, pub Color);
 
+impl CPiece {
+    /// Returns the piece described by the FEN letter `c`. Upper case
+    /// letters are white pieces and lower case letters are black pieces.
+    pub fn from_fen_char(c: char) -> Option<CPiece> {
+        let piece = match c.to_ascii_lowercase() {
+            'p' => Piece::Pawn,
+            'n' => Piece::Knight,
+            'b' => Piece::Bishop,
+            'r' => Piece::Rook,
+            'q' => Piece::Queen,
+            'k' => Piece::King,
+            _ => return None,
+        };
+        let color = if c.is_ascii_uppercase() {
+            Color::White
+        } else {
+            Color::Black
+        };
+        Some(CPiece(piece, color))
+    }
+
+    /// Returns the FEN letter for this piece
+    pub fn to_fen_char(&self) -> char {
+        let c = match self.0 {
+            Piece::Pawn => 'p',
+            Piece::Knight => 'n',
+            Piece::Bishop => 'b',
+            Piece::Rook => 'r',
+            Piece::Queen => 'q',
+            Piece::King => 'k',
+        };
+        match self.1 {
+            Color::White => c.to_ascii_uppercase(),
+            Color::Black => c,
+        }
+    }
+}
+
 /// All eight cardinal directions
 #[derive(Clone, Copy, FromPrimitive, Debug)]
 pub enum Dir {
@@ -47,22 +104,16 @@ pub enum Dir {
 
 impl Dir {
     pub fn neg(&self) -> bool {
-        match *self {
-            West | Sout | Sowe | Soea => true,
-            _ => false,
-        }
+        matches!(*self, West | Sout | Sowe | Soea)
     }
 
     pub fn pos(&self) -> bool {
-        match *self {
-            West | Sout | Sowe | Soea => false,
-            _ => true,
-        }
+        !self.neg()
     }
 }
 
 /// All squares on a chess board
-#[derive(Clone, Copy, FromPrimitive, Debug)]
+#[derive(Clone, Copy, FromPrimitive, Debug, PartialEq, Eq)]
 pub enum Square {
     A1,
     B1,
@@ -131,6 +182,37 @@ pub enum Square {
 }
 
 impl Square {
+    /// Returns the square on file `file` and rank `rank`, both counted
+    /// from zero, or `None` if either is off the board
+    pub fn from_file_rank(file: u8, rank: u8) -> Option<Square> {
+        if file < 8 && rank < 8 {
+            FromPrimitive::from_u8(rank * 8 + file)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the square named `name` in algebraic notation, e.g. `e4`,
+    /// or `None` if `name` isn't a square
+    pub fn from_algebraic(name: &str) -> Option<Square> {
+        match name.as_bytes() {
+            [file @ b'a'..=b'h', rank @ b'1'..=b'8'] => {
+                Square::from_file_rank(file - b'a', rank - b'1')
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the file of this square, where 0 is the A file
+    pub fn file(&self) -> u8 {
+        *self as u8 & 7
+    }
+
+    /// Returns the rank of this square, where 0 is the first rank
+    pub fn rank(&self) -> u8 {
+        *self as u8 >> 3
+    }
+
     /// Returns a bitboard with a one set on this square and
     /// zeroes everywhere else
     pub fn as_bitboard(&self) -> Bitboard {
@@ -156,3 +238,10 @@ impl Square {
         FromPrimitive::from_i32(*self as i32 + amount * steps)
     }
 }
+
+/// Formats the square in algebraic notation, e.g. `e4`
+impl fmt::Display for Square {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", (b'a' + self.file()) as char, self.rank() + 1)
+    }
+}
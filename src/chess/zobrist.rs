@@ -0,0 +1,55 @@
+//! Random keys for Zobrist hashing. A position's hash is the XOR of the keys
+//! for each piece on its square, the side to move if it is black, the castling
+//! rights and the file of the pawn that can be captured en passant, if a pawn
+//! is there to capture it. Since XOR is its own inverse, the hash can be
+//! updated incrementally as moves are made and unmade by XORing in and out the
+//! keys that change.
+
+/// Seed for the key generator. Changing it changes every hash.
+const SEED: u64 = 0x2d35_8dcc_aa6c_78a5;
+
+/// Returns the next state and output of the splitmix64 generator
+const fn splitmix64(state: u64) -> (u64, u64) {
+    let state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    (state, z ^ (z >> 31))
+}
+
+/// Returns `N` keys generated from `seed`
+const fn keys<const N: usize>(seed: u64) -> [u64; N] {
+    let mut keys = [0; N];
+    let mut state = seed;
+    let mut i = 0;
+    while i < N {
+        let (next, key) = splitmix64(state);
+        state = next;
+        keys[i] = key;
+        i += 1;
+    }
+    keys
+}
+
+/// PIECE_KEYS\[Color * 6 + Piece]\[Square] is the key for that piece
+/// of that color on that square
+pub const PIECE_KEYS: [[u64; 64]; 12] = {
+    let mut piece_keys = [[0; 64]; 12];
+    let mut i = 0;
+    while i < 12 {
+        piece_keys[i] = keys(SEED ^ i as u64);
+        i += 1;
+    }
+    piece_keys
+};
+
+/// CASTLING_KEYS\[rights] is the key for the castling rights `rights`,
+/// in the same format as `Board`'s castling rights
+pub const CASTLING_KEYS: [u64; 16] = keys(SEED ^ 12);
+
+/// EN_PASSANT_KEYS\[file] is the key for a pawn on file `file` that can be
+/// captured en passant
+pub const EN_PASSANT_KEYS: [u64; 8] = keys(SEED ^ 13);
+
+/// The key for black being the side to move
+pub const SIDE_KEY: u64 = keys::<1>(SEED ^ 14)[0];
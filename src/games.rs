@@ -0,0 +1,312 @@
+use crate::api::{self, ApiError, MoveJson};
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::fs::{self, OpenOptions};
+use tokio::sync::{Mutex, OwnedMutexGuard};
+use website::chess::board::{Board, Status};
+use website::chess::pgn;
+use website::chess::utils::Color;
+
+/// Correspondence games, saved as one JSON file per game in `dir`
+///
+/// # Fields
+///
+/// * `dir` - the directory the games are saved in, created on the first save
+/// * `locks` - a lock for each game, held while the game is read, changed
+///   and saved, so that two moves sent at once can't both be played. Only
+///   the games someone holds or waits on a lock for are kept.
+struct GameStore {
+    dir: PathBuf,
+    locks: std::sync::Mutex<HashMap<String, Arc<Mutex<()>>>>,
+}
+
+type Store = Arc<GameStore>;
+
+/// A game as it is saved
+///
+/// # Fields
+///
+/// * `moves` - the moves played from the starting position, in UCI notation
+/// * `white` - the secret token white's moves are sent with
+/// * `black` - the secret token black's moves are sent with, once someone
+///   has joined as black
+#[derive(Serialize, Deserialize)]
+struct SavedGame {
+    moves: Vec<String>,
+    white: String,
+    black: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct GameMoveRequest {
+    /// The move in UCI notation, e.g. `e2e4`, or SAN, e.g. `e4`
+    #[serde(rename = "move")]
+    m: String,
+    /// The number of moves the player saw played before theirs. If given,
+    /// the move is refused when the game has moved on since.
+    ply: Option<usize>,
+    /// The token the player was given for their side
+    token: Option<String>,
+}
+
+/// The token of the player asking for a game, if they play in it
+#[derive(Deserialize)]
+pub struct GameQuery {
+    token: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct GameResponse {
+    id: String,
+    fen: String,
+    moves: Vec<MoveJson>,
+    status: &'static str,
+    /// The side the player asking plays, left out for anyone else
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<&'static str>,
+}
+
+/// A game with the token a player was given for their side. The player can
+/// come back to the game from anywhere with the token.
+#[derive(Serialize)]
+pub struct PlayerResponse {
+    #[serde(flatten)]
+    game: GameResponse,
+    token: String,
+}
+
+pub fn routes_games(dir: PathBuf) -> Router {
+    let store = Arc::new(GameStore {
+        dir,
+        locks: Default::default(),
+    });
+    Router::new()
+        .route("/api/chess/games", post(create_handler))
+        .route("/api/chess/games/:id", get(game_handler))
+        .route("/api/chess/games/:id/join", post(join_handler))
+        .route("/api/chess/games/:id/moves", post(move_handler))
+        .route("/api/chess/games/:id/pgn", get(pgn_handler))
+        .with_state(store)
+}
+
+/// Starts a game with a new random ID, played at `/chess/game/{id}`. The
+/// player starting it plays white.
+async fn create_handler(State(store): State<Store>) -> Result<Json<PlayerResponse>, ApiError> {
+    let game = SavedGame {
+        moves: vec![],
+        white: new_token(),
+        black: None,
+    };
+    let id = store.create(&game).await?;
+    Ok(Json(PlayerResponse {
+        game: response(id, &game, Some(&game.white))?,
+        token: game.white.clone(),
+    }))
+}
+
+/// Gives black to the first player to ask for it after the game is started
+async fn join_handler(
+    State(store): State<Store>,
+    Path(id): Path<String>,
+) -> Result<Json<PlayerResponse>, ApiError> {
+    let _lock = store.lock(&id).await;
+    let mut game = store.load(&id).await?;
+    if game.black.is_some() {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "both sides are already taken",
+        ));
+    }
+    let token = new_token();
+    game.black = Some(token.clone());
+    store.save(&id, &game).await?;
+    Ok(Json(PlayerResponse {
+        game: response(id, &game, Some(&token))?,
+        token,
+    }))
+}
+
+async fn game_handler(
+    State(store): State<Store>,
+    Path(id): Path<String>,
+    Query(query): Query<GameQuery>,
+) -> Result<Json<GameResponse>, ApiError> {
+    let game = store.load(&id).await?;
+    Ok(Json(response(id, &game, query.token.as_deref())?))
+}
+
+/// Plays a move for the side to move, if the request has that side's token
+async fn move_handler(
+    State(store): State<Store>,
+    Path(id): Path<String>,
+    Json(request): Json<GameMoveRequest>,
+) -> Result<Json<GameResponse>, ApiError> {
+    let _lock = store.lock(&id).await;
+    let mut game = store.load(&id).await?;
+    let (board, _) = replay(&game)?;
+    let side = request
+        .token
+        .as_deref()
+        .and_then(|token| side_of(&game, token));
+    if side != Some(board.side_to_move()) {
+        return Err(ApiError::new(StatusCode::FORBIDDEN, "it isn't your move"));
+    }
+    if request.ply.is_some_and(|ply| ply != game.moves.len()) {
+        return Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "the game has moved on, reload it",
+        ));
+    }
+
+    if board.status() != Status::Ongoing {
+        return Err(ApiError::bad_request("the game is over"));
+    }
+    let m = board
+        .parse_uci_move(&request.m)
+        .or_else(|_| board.parse_san(&request.m))
+        .map_err(ApiError::bad_request)?;
+    game.moves.push(m.to_uci());
+    store.save(&id, &game).await?;
+    Ok(Json(response(id, &game, request.token.as_deref())?))
+}
+
+async fn pgn_handler(
+    State(store): State<Store>,
+    Path(id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let game = store.load(&id).await?;
+    let (board, _) = replay(&game)?;
+    let mut pgn_game = pgn::Game::from_board(&board);
+    pgn_game.set_tag("Event", "Correspondence game");
+    pgn_game.set_tag("Site", &format!("/chess/game/{id}"));
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-chess-pgn")],
+        pgn_game.to_pgn(),
+    ))
+}
+
+impl GameStore {
+    /// Locks game `id` until the returned guard is dropped
+    async fn lock(&self, id: &str) -> OwnedMutexGuard<()> {
+        let lock = {
+            let mut locks = self.locks.lock().unwrap();
+            // A lock only the map refers to is neither held nor waited on
+            locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+            locks.entry(id.to_string()).or_default().clone()
+        };
+        lock.lock_owned().await
+    }
+
+    /// Returns the file game `id` is saved in. IDs are checked to be what
+    /// `create_handler` makes, so that they can't point outside `dir`.
+    fn path(&self, id: &str) -> Result<PathBuf, ApiError> {
+        if id.len() != 16 || !id.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(not_found());
+        }
+        Ok(self.dir.join(format!("{id}.json")))
+    }
+
+    async fn load(&self, id: &str) -> Result<SavedGame, ApiError> {
+        let json = match fs::read(self.path(id)?).await {
+            Ok(json) => json,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Err(not_found()),
+            Err(err) => return Err(internal(err)),
+        };
+        serde_json::from_slice(&json).map_err(internal)
+    }
+
+    /// Saves `game` under a new random ID and returns the ID. Creating the
+    /// file fails if it exists, so that two games can't get the same ID.
+    async fn create(&self, game: &SavedGame) -> Result<String, ApiError> {
+        fs::create_dir_all(&self.dir).await.map_err(internal)?;
+        let id = loop {
+            let id = format!("{:016x}", rand::random::<u64>());
+            let file = OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(self.path(&id)?)
+                .await;
+            match file {
+                Ok(_) => break id,
+                Err(err) if err.kind() == ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(internal(err)),
+            }
+        };
+        self.save(&id, game).await?;
+        Ok(id)
+    }
+
+    /// Saves game `id`, writing to a temporary file first so that a crash
+    /// can't leave the game half written
+    async fn save(&self, id: &str, game: &SavedGame) -> Result<(), ApiError> {
+        let path = self.path(id)?;
+        let temp = path.with_extension("json.tmp");
+        let json = serde_json::to_vec(game).map_err(internal)?;
+        fs::create_dir_all(&self.dir).await.map_err(internal)?;
+        fs::write(&temp, json).await.map_err(internal)?;
+        fs::rename(&temp, &path).await.map_err(internal)
+    }
+}
+
+/// Returns a random token that can't be guessed
+fn new_token() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+/// Returns the side `token` was given for in `game`, if any
+fn side_of(game: &SavedGame, token: &str) -> Option<Color> {
+    if token == game.white {
+        Some(Color::White)
+    } else if game.black.as_deref() == Some(token) {
+        Some(Color::Black)
+    } else {
+        None
+    }
+}
+
+/// Returns the position after the moves of `game`, and the moves as they
+/// are sent to the players
+fn replay(game: &SavedGame) -> Result<(Board, Vec<MoveJson>), ApiError> {
+    let mut board = Board::new();
+    let mut moves = vec![];
+    for uci_move in &game.moves {
+        let m = board.parse_uci_move(uci_move).map_err(internal)?;
+        moves.push(api::move_json(&board, m));
+        board.make_move_mut(&m);
+    }
+    Ok((board, moves))
+}
+
+/// Returns `game` as it is sent to the player with `token`
+fn response(id: String, game: &SavedGame, token: Option<&str>) -> Result<GameResponse, ApiError> {
+    let (board, moves) = replay(game)?;
+    let side = token.and_then(|token| side_of(game, token));
+    Ok(GameResponse {
+        id,
+        fen: board.to_fen(),
+        moves,
+        status: api::status_name(board.status()),
+        side: side.map(|side| match side {
+            Color::White => "w",
+            Color::Black => "b",
+        }),
+    })
+}
+
+fn not_found() -> ApiError {
+    ApiError::new(StatusCode::NOT_FOUND, "no such game")
+}
+
+fn internal(err: impl ToString) -> ApiError {
+    ApiError::new(StatusCode::INTERNAL_SERVER_ERROR, err)
+}
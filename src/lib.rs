@@ -3,7 +3,7 @@
 //! was obtained from the official chess programming wiki:
 //! <https://www.chessprogramming.org/Bitboards>
 //!
-mod chess;
+pub mod chess;
 
 extern crate num;
 #[macro_use]
@@ -2,18 +2,26 @@ use axum::Router;
 use std::env;
 use tokio::net::TcpListener;
 
+mod api;
+mod games;
 mod services;
+mod ws;
 
 // setup constants
 const PORT: &str = "8080";
 const HOST: &str = "0.0.0.0";
+const GAMES_DIR: &str = "games";
 
 #[tokio::main]
 async fn main() {
-    let (port, host) = from_env();
+    let (port, host, games_dir) = from_env();
     let addr = format!("{}:{}", host, port);
 
-    let routes_all = Router::new().merge(services::routes_public());
+    let routes_all = Router::new()
+        .merge(api::routes_api())
+        .merge(ws::routes_ws())
+        .merge(games::routes_games(games_dir.into()))
+        .merge(services::routes_public());
 
     let listener = TcpListener::bind(addr).await.unwrap();
 
@@ -23,12 +31,15 @@ async fn main() {
 }
 
 /// variables from environment or default to configure server
-/// port, host
-fn from_env() -> (String, String) {
+/// port, host, directory correspondence games are saved in
+fn from_env() -> (String, String, String) {
     (
         env::var("PORT").ok().unwrap_or_else(|| PORT.to_string()),
         env::var("SERVER_HOST")
             .ok()
             .unwrap_or_else(|| HOST.to_string()),
+        env::var("GAMES_DIR")
+            .ok()
+            .unwrap_or_else(|| GAMES_DIR.to_string()),
     )
 }
@@ -1 +1,187 @@
-const board = Chessboard('board', 'start');
\ No newline at end of file
+// A game against the engine over the /ws/chess WebSocket. The server keeps
+// the game, checks every move and streams the engine's search while it
+// thinks, so the page only shows what it is sent. The session ID is kept for
+// the tab, so reloading the page picks the game back up.
+
+// Search limits for each difficulty, from the select's values
+const LEVELS = {
+  1: { depth: 1, movetime: 1000 },
+  2: { depth: 2, movetime: 1000 },
+  3: { depth: 4, movetime: 1000 },
+  4: { movetime: 1000 },
+  5: { movetime: 3000 },
+};
+
+const DRAWS = {
+  stalemate: 'Draw by stalemate',
+  draw_fifty_move: 'Draw by the fifty-move rule',
+  draw_repetition: 'Draw by threefold repetition',
+  draw_insufficient_material: 'Draw by insufficient material',
+};
+
+const game = {
+  fen: null,
+  player: sessionStorage.getItem('chess-player') || 'w',
+  over: false,
+  // Whether a move or the engine's reply is on its way, during which
+  // nothing can be dragged
+  waiting: true,
+};
+
+const board = Chessboard('board', {
+  position: 'start',
+  draggable: true,
+  onDragStart: onDragStart,
+  onDrop: onDrop,
+});
+
+let socket = connect();
+
+$('#side').val(game.player);
+$('#new-game').on('click', newGame);
+$('#play-friend').on('click', playFriend);
+$(window).on('resize', board.resize);
+
+function connect() {
+  const protocol = location.protocol === 'https:' ? 'wss://' : 'ws://';
+  const id = sessionStorage.getItem('chess-session');
+  const query = id ? '?session=' + encodeURIComponent(id) : '';
+  const ws = new WebSocket(protocol + location.host + '/ws/chess' + query);
+  ws.onmessage = function (event) {
+    receive(JSON.parse(event.data));
+  };
+  ws.onclose = function () {
+    game.waiting = true;
+    setMessage('Lost contact with the server, reconnecting...');
+    setTimeout(function () {
+      socket = connect();
+    }, 2000);
+  };
+  return ws;
+}
+
+function send(message) {
+  socket.send(JSON.stringify(message));
+}
+
+function limits() {
+  return LEVELS[$('#difficulty').val()];
+}
+
+function receive(message) {
+  switch (message.type) {
+    case 'session':
+      sessionStorage.setItem('chess-session', message.id);
+      game.over = false;
+      setMessage('');
+      setThinking('');
+      board.orientation(game.player === 'w' ? 'white' : 'black');
+      update(message.fen, message.status);
+      game.waiting = false;
+      // The engine moves first when the player is black, or when the
+      // player left while it was its turn
+      if (!game.over && sideToMove() !== game.player) {
+        engineMove();
+      }
+      break;
+    case 'move':
+      update(message.fen, message.status);
+      if (message.by === 'engine' || game.over) {
+        setThinking('');
+        game.waiting = false;
+      }
+      break;
+    case 'info':
+      showInfo(message);
+      break;
+    case 'error':
+      // A rejected move puts the piece back
+      board.position(game.fen.split(' ')[0]);
+      setMessage(message.error);
+      game.waiting = false;
+      break;
+  }
+}
+
+function sideToMove() {
+  return game.fen.split(' ')[1];
+}
+
+// Shows the position after a move, announcing the end of the game
+function update(fen, status) {
+  game.fen = fen;
+  // Castling, en passant and promotion move more than the dragged piece
+  board.position(fen.split(' ')[0]);
+  if (status === 'ongoing') {
+    return;
+  }
+  game.over = true;
+  if (status === 'checkmate') {
+    // The side to move is the one mated
+    const winner = sideToMove() === 'w' ? 'Black' : 'White';
+    setMessage('Checkmate, ' + winner + ' wins');
+  } else {
+    setMessage(DRAWS[status]);
+  }
+}
+
+function onDragStart(source, piece) {
+  return !game.over && !game.waiting
+    && sideToMove() === game.player && piece[0] === game.player;
+}
+
+function onDrop(source, target, piece) {
+  if (target === 'offboard' || source === target) {
+    return 'snapback';
+  }
+  // Pawns always promote to queens
+  let uci = source + target;
+  if (piece[1] === 'P' && (target[1] === '8' || target[1] === '1')) {
+    uci += 'q';
+  }
+  // The piece stays where it was dropped until the server says otherwise
+  game.waiting = true;
+  setMessage('');
+  send(Object.assign({ type: 'move', move: uci }, limits()));
+}
+
+function engineMove() {
+  game.waiting = true;
+  send(Object.assign({ type: 'go' }, limits()));
+}
+
+// Shows the engine's progress, with the score from its side
+function showInfo(info) {
+  let score;
+  if (info.mate !== null) {
+    score = 'mate in ' + info.mate;
+  } else {
+    score = (info.score > 0 ? '+' : '') + (info.score / 100).toFixed(2);
+  }
+  setThinking('Depth ' + info.depth + ', ' + score + ': ' + info.pv.join(' '));
+}
+
+function setMessage(message) {
+  $('#message').text(message);
+}
+
+function setThinking(thinking) {
+  $('#thinking').text(thinking);
+}
+
+function newGame() {
+  if (game.waiting) {
+    return;
+  }
+  game.player = $('#side').val();
+  sessionStorage.setItem('chess-player', game.player);
+  game.waiting = true;
+  send({ type: 'new' });
+}
+
+// Starts a correspondence game, which the player creating it plays as white
+function playFriend() {
+  $.post('/api/chess/games').then(function (response) {
+    location.href = '/chess/game/' + response.id + '?token=' + response.token;
+  });
+}
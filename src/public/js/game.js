@@ -0,0 +1,159 @@
+// A correspondence game between two people at /chess/game/{id}. The game is
+// kept on the server, which checks every move, and the page checks for the
+// opponent's moves every few seconds. Whoever creates the game plays white
+// and whoever opens the link after joins as black. Each side is given a
+// secret token that its moves are sent with, and its own link,
+// /chess/game/{id}?token={token}, to come back to the game from any browser.
+// The browser remembers the token as well, so the plain link works there
+// too; anyone else opening it watches the game.
+
+const POLL_INTERVAL_MS = 5000;
+
+const DRAWS = {
+  stalemate: 'Draw by stalemate',
+  draw_fifty_move: 'Draw by the fifty-move rule',
+  draw_repetition: 'Draw by threefold repetition',
+  draw_insufficient_material: 'Draw by insufficient material',
+};
+
+const id = location.pathname.split('/').pop();
+const api = '/api/chess/games/' + id;
+const invite = location.origin + location.pathname;
+
+// The player's token, or null when watching
+let token = new URLSearchParams(location.search).get('token')
+  || localStorage.getItem('chess-game-' + id);
+// The side the token plays, once the server has said which
+let side = null;
+
+const game = {
+  fen: null,
+  ply: 0,
+  status: 'ongoing',
+  // Whether a move is waiting on the server, during which nothing can be
+  // dragged
+  waiting: false,
+};
+
+const board = Chessboard('board', {
+  draggable: true,
+  pieceTheme: '/img/chesspieces/wikipedia/{piece}.png',
+  onDragStart: onDragStart,
+  onDrop: onDrop,
+});
+
+$('#link').text(invite).attr('href', invite);
+$('#pgn').attr('href', api + '/pgn').attr('download', id + '.pgn');
+$(window).on('resize', board.resize);
+
+if (token) {
+  load();
+} else {
+  join();
+}
+setInterval(function () {
+  if (!game.waiting && game.status === 'ongoing' && !isPlayersMove()) {
+    load();
+  }
+}, POLL_INTERVAL_MS);
+
+// Joins the game as black, or watches it if black is taken already
+function join() {
+  $.post(api + '/join').then(function (response) {
+    token = response.token;
+    update(response);
+  }, load);
+}
+
+function load() {
+  $.getJSON(api, token ? { token: token } : {}).then(update, function () {
+    setMessage('There is no game at this link');
+  });
+}
+
+function sideToMove() {
+  return game.fen.split(' ')[1];
+}
+
+function isPlayersMove() {
+  return side !== null && sideToMove() === side;
+}
+
+// Keeps the player's own link in the address bar and on the page, and the
+// token in the browser
+function remember() {
+  const link = invite + '?token=' + token;
+  localStorage.setItem('chess-game-' + id, token);
+  history.replaceState(null, '', link);
+  $('#resume-link').text(link).attr('href', link);
+  $('#resume').removeClass('hidden');
+}
+
+function update(response) {
+  if (response.side && side === null) {
+    side = response.side;
+    board.orientation(side === 'b' ? 'black' : 'white');
+    remember();
+  }
+  game.fen = response.fen;
+  game.ply = response.moves.length;
+  game.status = response.status;
+  board.position(game.fen.split(' ')[0]);
+
+  $('#moves').text(response.moves.map(function (m, ply) {
+    return (ply % 2 === 0 ? (ply / 2 + 1) + '. ' : '') + m.san;
+  }).join(' '));
+
+  if (game.status === 'checkmate') {
+    // The side to move is the one mated
+    const winner = sideToMove() === 'w' ? 'Black' : 'White';
+    setMessage('Checkmate, ' + winner + ' wins');
+  } else if (game.status !== 'ongoing') {
+    setMessage(DRAWS[game.status]);
+  } else if (isPlayersMove()) {
+    setMessage('Your move');
+  } else if (side) {
+    setMessage('Waiting for your opponent');
+  } else {
+    setMessage((sideToMove() === 'w' ? 'White' : 'Black') + ' to move');
+  }
+}
+
+function onDragStart(source, piece) {
+  return game.fen !== null && !game.waiting && game.status === 'ongoing'
+    && isPlayersMove() && piece[0] === side;
+}
+
+function onDrop(source, target, piece) {
+  if (target === 'offboard' || source === target) {
+    return 'snapback';
+  }
+  // Pawns always promote to queens
+  let uci = source + target;
+  if (piece[1] === 'P' && (target[1] === '8' || target[1] === '1')) {
+    uci += 'q';
+  }
+
+  game.waiting = true;
+  $.ajax({
+    url: api + '/moves',
+    method: 'POST',
+    contentType: 'application/json',
+    data: JSON.stringify({ move: uci, ply: game.ply, token: token }),
+  })
+    .then(update, function (xhr) {
+      // A rejected move puts the piece back, and a game that moved on is
+      // shown as it is now
+      board.position(game.fen.split(' ')[0]);
+      if (xhr.status === 409) {
+        load();
+      }
+    })
+    .always(function () {
+      game.waiting = false;
+    });
+}
+
+function setMessage(message) {
+  $('#message').text(message);
+}
@@ -34,6 +34,7 @@ pub async fn public_handler(uri: Uri) -> Result<impl IntoResponse, impl IntoResp
     let path = match uri.path() {
         "/" => "/html/index.html",
         "/chess" => "/html/chess.html",
+        path if path.starts_with("/chess/game/") => "/html/game.html",
         "/cmu-15-418-s24-final-project" => "/html/cmu-15-418-s24-final-project.html",
         "/dijkstra" => "/html/dijkstra.html",
         "/optcomp" => "/html/optcomp.html",
@@ -52,7 +53,7 @@ pub async fn public_handler(uri: Uri) -> Result<impl IntoResponse, impl IntoResp
         _ => return Err(NotFoundError),
     };
 
-    let asset = Assets::get(&path).ok_or(NotFoundError)?;
+    let asset = Assets::get(path).ok_or(NotFoundError)?;
 
     Ok(Response::builder()
         .status(StatusCode::OK)
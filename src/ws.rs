@@ -0,0 +1,385 @@
+use crate::api::{self, MoveJson};
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Query, State,
+    },
+    response::Response,
+    routing::get,
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tokio::{task, time};
+use website::chess::board::{Board, Status};
+use website::chess::cmove::CMove;
+use website::chess::engine::{self, SearchLimits, SearchResult};
+use website::chess::tt::TranspositionTable;
+
+/// How long a session is kept without hearing from its client
+const SESSION_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+/// How often sessions are checked for having expired
+const REAP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A game kept on the server, which a client can leave and come back to
+/// until it expires
+///
+/// # Fields
+///
+/// * `board` - the position, with the game's history for spotting repetitions
+/// * `moves` - the moves played in UCI notation, sent to resuming clients
+/// * `last_active` - when the client was last heard from or sent a move
+/// * `generation` - counts the changes to the game, so that a search can
+///   tell whether the position it searched is still the one on the board
+struct Session {
+    board: Board,
+    moves: Vec<String>,
+    last_active: Instant,
+    generation: u64,
+}
+
+type Sessions = Arc<Mutex<HashMap<String, Session>>>;
+
+#[derive(Deserialize)]
+pub struct ConnectQuery {
+    /// The ID of a session to resume. A new session is started if left out
+    /// or if the session has expired.
+    session: Option<String>,
+}
+
+/// What a client can send, as JSON tagged by `type`
+#[derive(Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    /// Starts a new game from `fen`, or the starting position
+    New { fen: Option<String> },
+    /// Plays a move in UCI notation or SAN, to which the engine replies
+    Move {
+        #[serde(rename = "move")]
+        m: String,
+        depth: Option<u32>,
+        movetime: Option<u64>,
+    },
+    /// Has the engine move, e.g. when the client plays black
+    Go {
+        depth: Option<u32>,
+        movetime: Option<u64>,
+    },
+    /// Has the engine play the best move it has found so far
+    Stop,
+}
+
+/// What the server sends, as JSON tagged by `type`
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    /// The session's game, sent on connecting and on starting a new game
+    Session {
+        id: String,
+        fen: String,
+        moves: Vec<String>,
+        status: &'static str,
+    },
+    /// A move played, `by` the player or the engine
+    Move {
+        by: &'static str,
+        #[serde(rename = "move")]
+        m: MoveJson,
+        fen: String,
+        status: &'static str,
+    },
+    /// The result of an iteration of the engine's search
+    Info {
+        depth: u32,
+        score: Option<i32>,
+        mate: Option<i32>,
+        nodes: u64,
+        pv: Vec<String>,
+    },
+    Error {
+        error: String,
+    },
+}
+
+/// Sent from the search thread to the connection
+enum SearchEvent {
+    Info(SearchResult),
+    Done(SearchResult),
+}
+
+pub fn routes_ws() -> Router {
+    let sessions = Sessions::default();
+    tokio::spawn(reap_sessions(sessions.clone()));
+    Router::new()
+        .route("/ws/chess", get(ws_handler))
+        .with_state(sessions)
+}
+
+/// Removes sessions that haven't been active for `SESSION_TIMEOUT`
+async fn reap_sessions(sessions: Sessions) {
+    let mut interval = time::interval(REAP_INTERVAL);
+    loop {
+        interval.tick().await;
+        sessions
+            .lock()
+            .unwrap()
+            .retain(|_, session| session.last_active.elapsed() < SESSION_TIMEOUT);
+    }
+}
+
+async fn ws_handler(
+    ws: WebSocketUpgrade,
+    State(sessions): State<Sessions>,
+    Query(query): Query<ConnectQuery>,
+) -> Response {
+    ws.on_upgrade(move |socket| handle_socket(socket, sessions, query.session))
+}
+
+/// Plays the session's game over `socket` until the client leaves or goes
+/// quiet for `SESSION_TIMEOUT`
+async fn handle_socket(mut socket: WebSocket, sessions: Sessions, resume: Option<String>) {
+    let id = {
+        let mut sessions = sessions.lock().unwrap();
+        match resume.filter(|id| sessions.contains_key(id)) {
+            Some(id) => id,
+            None => {
+                let id = format!("{:016x}", rand::random::<u64>());
+                sessions.insert(id.clone(), new_session(Board::new()));
+                id
+            }
+        }
+    };
+    let mut connection = Connection {
+        id,
+        sessions,
+        search: None,
+        events: mpsc::unbounded_channel(),
+    };
+
+    let mut replies = vec![connection.session_message()];
+    loop {
+        for reply in replies.drain(..) {
+            let text = serde_json::to_string(&reply).unwrap();
+            if socket.send(Message::Text(text)).await.is_err() {
+                connection.stop();
+                return;
+            }
+        }
+
+        tokio::select! {
+            message = time::timeout(SESSION_TIMEOUT, socket.recv()) => {
+                let text = match message {
+                    Ok(Some(Ok(Message::Text(text)))) => text,
+                    Ok(Some(Ok(_))) => continue,
+                    // Timed out, closed or broken
+                    _ => break,
+                };
+                replies = match serde_json::from_str(&text) {
+                    Ok(message) => connection.handle(message),
+                    Err(err) => vec![error(err)],
+                };
+            }
+            Some(event) = connection.events.1.recv() => {
+                replies = connection.search_event(event).into_iter().collect();
+            }
+        }
+    }
+    connection.stop();
+}
+
+fn new_session(board: Board) -> Session {
+    Session {
+        board,
+        moves: vec![],
+        last_active: Instant::now(),
+        generation: 0,
+    }
+}
+
+fn error(err: impl ToString) -> ServerMessage {
+    ServerMessage::Error {
+        error: err.to_string(),
+    }
+}
+
+fn expired() -> ServerMessage {
+    error("the session has expired")
+}
+
+/// A client's connection to its session
+///
+/// # Fields
+///
+/// * `id` - the session's ID
+/// * `sessions` - every session, locked only for as long as it takes to
+///   update one
+/// * `search` - the stop flag of the engine's search in progress, if any,
+///   and the session's generation when it started
+/// * `events` - the channel the search sends its progress down
+struct Connection {
+    id: String,
+    sessions: Sessions,
+    search: Option<(Arc<AtomicBool>, u64)>,
+    events: (
+        UnboundedSender<SearchEvent>,
+        mpsc::UnboundedReceiver<SearchEvent>,
+    ),
+}
+
+impl Connection {
+    /// Runs `f` on the session, marking it active. Returns None if it has
+    /// expired.
+    fn with_session<T>(&self, f: impl FnOnce(&mut Session) -> T) -> Option<T> {
+        let mut sessions = self.sessions.lock().unwrap();
+        let session = sessions.get_mut(&self.id)?;
+        session.last_active = Instant::now();
+        Some(f(session))
+    }
+
+    /// Returns the session's game, or an error if it has expired
+    fn session_message(&self) -> ServerMessage {
+        self.with_session(|session| ServerMessage::Session {
+            id: self.id.clone(),
+            fen: session.board.to_fen(),
+            moves: session.moves.clone(),
+            status: api::status_name(session.board.status()),
+        })
+        .unwrap_or_else(expired)
+    }
+
+    /// Returns the replies to `message`
+    fn handle(&mut self, message: ClientMessage) -> Vec<ServerMessage> {
+        match message {
+            ClientMessage::Stop => {
+                if let Some((stop, _)) = &self.search {
+                    stop.store(true, Ordering::Relaxed);
+                }
+                vec![]
+            }
+            _ if self.search.is_some() => vec![error("the engine is thinking")],
+            ClientMessage::New { fen } => {
+                let board = match fen.map_or(Ok(Board::new()), |fen| Board::from_fen(&fen)) {
+                    Ok(board) => board,
+                    Err(err) => return vec![error(err)],
+                };
+                self.with_session(|session| {
+                    *session = Session {
+                        generation: session.generation + 1,
+                        ..new_session(board)
+                    }
+                });
+                vec![self.session_message()]
+            }
+            ClientMessage::Move { m, depth, movetime } => {
+                let played = self.with_session(|session| {
+                    if session.board.status() != Status::Ongoing {
+                        return Err(error("the game is over"));
+                    }
+                    let board = &session.board;
+                    let m = board
+                        .parse_uci_move(&m)
+                        .or_else(|_| board.parse_san(&m))
+                        .map_err(error)?;
+                    Ok(play(session, "player", m))
+                });
+                match played {
+                    Some(Ok(reply)) => {
+                        // The engine has nothing to reply to a game ending move
+                        let _ = self.go(depth, movetime);
+                        vec![reply]
+                    }
+                    Some(Err(err)) => vec![err],
+                    None => vec![expired()],
+                }
+            }
+            ClientMessage::Go { depth, movetime } => match self.go(depth, movetime) {
+                Ok(()) => vec![],
+                Err(err) => vec![err],
+            },
+        }
+    }
+
+    /// Starts the engine searching the session's position on the blocking
+    /// thread pool, unless the game is over
+    fn go(&mut self, depth: Option<u32>, movetime: Option<u64>) -> Result<(), ServerMessage> {
+        let (board, generation) = self
+            .with_session(|session| (session.board.clone(), session.generation))
+            .ok_or_else(expired)?;
+        if board.status() != Status::Ongoing {
+            return Err(error("the game is over"));
+        }
+        let limits = api::search_limits(depth, movetime);
+        self.search = Some((limits.stop.clone(), generation));
+        let events = self.events.0.clone();
+        task::spawn_blocking(move || search(board, limits, events));
+        Ok(())
+    }
+
+    /// Returns the message for `event`, playing the engine's move when the
+    /// search is done. Another connection to the session may have changed
+    /// the game during the search, in which case the move is dropped and the
+    /// game as it is now is sent instead.
+    fn search_event(&mut self, event: SearchEvent) -> Option<ServerMessage> {
+        match event {
+            SearchEvent::Info(result) => {
+                let (score, mate) = api::score_json(result.score);
+                Some(ServerMessage::Info {
+                    depth: result.depth,
+                    score,
+                    mate,
+                    nodes: result.nodes,
+                    pv: result.pv.iter().map(|m| m.to_uci()).collect(),
+                })
+            }
+            SearchEvent::Done(result) => {
+                let (_, generation) = self.search.take()?;
+                let m = result.best_move?;
+                let played = self.with_session(|session| {
+                    let current = session.generation == generation
+                        && session.board.generate_moves().contains(&m);
+                    current.then(|| play(session, "engine", m))
+                });
+                match played {
+                    Some(Some(reply)) => Some(reply),
+                    Some(None) => Some(self.session_message()),
+                    None => Some(expired()),
+                }
+            }
+        }
+    }
+
+    /// Stops the search in progress, if any
+    fn stop(&mut self) {
+        if let Some((stop, _)) = self.search.take() {
+            stop.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Plays `m` in the session, returning the message announcing it
+fn play(session: &mut Session, by: &'static str, m: CMove) -> ServerMessage {
+    let m_json = api::move_json(&session.board, m);
+    session.board.make_move_mut(&m);
+    session.moves.push(m.to_uci());
+    session.generation += 1;
+    ServerMessage::Move {
+        by,
+        m: m_json,
+        fen: session.board.to_fen(),
+        status: api::status_name(session.board.status()),
+    }
+}
+
+/// Searches `board`, sending each completed iteration and then the result
+/// down `events`
+fn search(mut board: Board, limits: SearchLimits, events: UnboundedSender<SearchEvent>) {
+    let tt = TranspositionTable::new(api::SEARCH_HASH_MB);
+    let result = engine::search_with_table(&mut board, &limits, &tt, 1, &mut |result| {
+        let _ = events.send(SearchEvent::Info(result.clone()));
+    });
+    let _ = events.send(SearchEvent::Done(result));
+}
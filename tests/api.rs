@@ -0,0 +1,90 @@
+mod common;
+
+use common::Server;
+
+fn query(fen: &str) -> String {
+    fen.replace(' ', "%20")
+}
+
+#[test]
+fn lists_legal_moves() {
+    let server = Server::start(&[]);
+    let (status, body) = server.json("GET", "/api/chess/moves", "");
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "ongoing");
+    assert_eq!(body["moves"].as_array().unwrap().len(), 20);
+    assert!(body["moves"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|m| m["uci"] == "g1f3" && m["san"] == "Nf3"));
+
+    let fen = "k7/8/1Q6/8/8/8/8/7K b - - 0 1";
+    let path = format!("/api/chess/moves?fen={}", query(fen));
+    let (_, body) = server.json("GET", &path, "");
+    assert_eq!(body["status"], "stalemate");
+    assert_eq!(body["moves"], serde_json::json!([]));
+
+    let (status, body) = server.json("GET", "/api/chess/moves?fen=garbage", "");
+    assert_eq!(status, 400);
+    assert!(body["error"].is_string());
+
+    // Well formed, but with no black king
+    let path = format!(
+        "/api/chess/moves?fen={}",
+        query("8/8/8/8/8/8/8/K7 w - - 0 1")
+    );
+    let (status, body) = server.json("GET", &path, "");
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "invalid position: black has 0 kings");
+}
+
+#[test]
+fn applies_moves() {
+    let server = Server::start(&[]);
+    let request = r#"{"fen": "rnbqkbnr/pppp1ppp/8/4p3/6P1/5P2/PPPPP2P/RNBQKBNR b KQkq - 0 2", "move": "d8h4"}"#;
+    let (status, body) = server.json("POST", "/api/chess/move", request);
+    assert_eq!(status, 200);
+    assert_eq!(body["move"]["san"], "Qh4#");
+    assert_eq!(body["status"], "checkmate");
+    assert_eq!(
+        body["fen"],
+        "rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3"
+    );
+
+    // SAN works too, and the starting position is the default
+    let (_, body) = server.json("POST", "/api/chess/move", r#"{"move": "e4"}"#);
+    assert_eq!(body["move"]["uci"], "e2e4");
+
+    let (status, body) = server.json("POST", "/api/chess/move", r#"{"move": "e5"}"#);
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "illegal move 'e5'");
+}
+
+#[test]
+fn replays_moves_before_the_move() {
+    let server = Server::start(&[]);
+    let shuffle = "g1f3 g8f6 f3g1 f6g8 g1f3 g8f6 f3g1";
+    let request = format!(r#"{{"moves": "{shuffle}", "move": "f6g8"}}"#);
+    let (status, body) = server.json("POST", "/api/chess/move", &request);
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "draw_repetition");
+
+    let (status, _) = server.json("GET", "/api/chess/moves?moves=e2e5", "");
+    assert_eq!(status, 400);
+}
+
+#[test]
+fn engine_replies() {
+    let server = Server::start(&[]);
+    let fen = "6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1";
+    let path = format!("/api/chess/bestmove?fen={}&movetime=200", query(fen));
+    let (status, body) = server.json("GET", &path, "");
+    assert_eq!(status, 200);
+    assert_eq!(body["move"]["uci"], "a1a8");
+    assert_eq!(body["mate"], 1);
+    assert_eq!(body["pv"][0], "a1a8");
+
+    let (_, body) = server.json("GET", "/api/chess/bestmove?moves=e2e4&depth=2", "");
+    assert_eq!(body["depth"], 2);
+}
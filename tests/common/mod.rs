@@ -0,0 +1,79 @@
+//! The website binary run as a server for the tests that talk to it over
+//! HTTP or WebSockets
+
+// Each test binary uses only some of these
+#![allow(dead_code)]
+
+use serde_json::Value;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Command};
+use std::thread;
+use std::time::Duration;
+
+/// The website binary listening on a free local port, killed when dropped
+pub struct Server {
+    child: Child,
+    pub port: u16,
+}
+
+impl Server {
+    /// Starts the server with the environment variables `env` set as well
+    /// as the port and host
+    pub fn start(env: &[(&str, &str)]) -> Server {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let child = Command::new(env!("CARGO_BIN_EXE_website"))
+            .env("PORT", port.to_string())
+            .env("SERVER_HOST", "127.0.0.1")
+            .envs(env.iter().copied())
+            .spawn()
+            .unwrap();
+        Server { child, port }
+    }
+
+    /// Opens a connection to the server, waiting for it to start listening
+    pub fn connect(&self) -> TcpStream {
+        (0..100)
+            .find_map(|_| {
+                TcpStream::connect(("127.0.0.1", self.port))
+                    .inspect_err(|_| thread::sleep(Duration::from_millis(50)))
+                    .ok()
+            })
+            .expect("server didn't start")
+    }
+
+    /// Sends a request and returns the status code and the body
+    pub fn request(&self, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = self.connect();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\
+             Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        let status = response[9..12].parse().unwrap();
+        let (_, body) = response.split_once("\r\n\r\n").unwrap();
+        (status, body.to_string())
+    }
+
+    /// Like `request`, parsing the body as JSON
+    pub fn json(&self, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let (status, body) = self.request(method, path, body);
+        (status, serde_json::from_str(&body).unwrap())
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
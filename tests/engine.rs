@@ -0,0 +1,172 @@
+use website::chess::board::Board;
+use website::chess::cmove::CMove;
+use website::chess::engine::{evaluate, mate_in, search, SearchLimits, MATE_SCORE};
+use website::chess::utils::{Piece, Square};
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+const POSITION3: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+const POSITION4: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+
+/// Returns `fen` with the board flipped vertically and the colors swapped
+fn mirror(fen: &str) -> String {
+    let fields: Vec<&str> = fen.split(' ').collect();
+    let swap_case = |s: &str| -> String {
+        s.chars()
+            .map(|c| match c.is_ascii_uppercase() {
+                true => c.to_ascii_lowercase(),
+                false => c.to_ascii_uppercase(),
+            })
+            .collect()
+    };
+    let placement: Vec<String> = fields[0].split('/').rev().map(swap_case).collect();
+    let side = if fields[1] == "w" { "b" } else { "w" };
+    let mut castling: Vec<char> = swap_case(fields[2]).chars().collect();
+    castling.sort_by_key(|c| (c.is_ascii_lowercase(), *c != 'K' && *c != 'k'));
+    let en_passant = match fields[3] {
+        "-" => "-".to_string(),
+        ep => format!("{}{}", &ep[..1], 9 - ep[1..].parse::<u8>().unwrap()),
+    };
+    format!(
+        "{} {} {} {} {}",
+        placement.join("/"),
+        side,
+        castling.into_iter().collect::<String>(),
+        en_passant,
+        fields[4..].join(" ")
+    )
+}
+
+/// Returns `m` in long algebraic notation, e.g. `e2e4` or `e7e8q`
+fn format_move(m: &CMove) -> String {
+    let square = |s: Square| format!("{}{}", (b'a' + s.file()) as char, s.rank() + 1);
+    let promo = match m.is_promo() {
+        Some(Piece::Knight) => "n",
+        Some(Piece::Bishop) => "b",
+        Some(Piece::Rook) => "r",
+        Some(Piece::Queen) => "q",
+        _ => "",
+    };
+    format!("{}{}{promo}", square(m.get_from()), square(m.get_to()))
+}
+
+#[test]
+fn start_position_is_equal() {
+    assert_eq!(evaluate(&Board::new()), 0);
+}
+
+#[test]
+fn mirrored_positions_evaluate_the_same() {
+    for fen in [KIWIPETE, POSITION3, POSITION4] {
+        let board = Board::from_fen(fen).unwrap();
+        let mirrored = Board::from_fen(&mirror(fen)).unwrap();
+        assert_eq!(evaluate(&board), evaluate(&mirrored), "{fen}");
+    }
+}
+
+#[test]
+fn score_is_from_side_to_move() {
+    let white = Board::from_fen(POSITION3).unwrap();
+    let black = Board::from_fen(&POSITION3.replace(" w ", " b ")).unwrap();
+    assert_eq!(evaluate(&white), -evaluate(&black));
+}
+
+#[test]
+fn extra_material_is_winning() {
+    // White is up a queen
+    let board =
+        Board::from_fen("rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1").unwrap();
+    assert!(evaluate(&board) > 800);
+    let board =
+        Board::from_fen("rnb1kbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1").unwrap();
+    assert!(evaluate(&board) < -800);
+}
+
+#[test]
+fn passed_pawn_beats_blocked_pawn() {
+    let passed = Board::from_fen("4k3/8/8/3P4/8/8/8/4K3 w - - 0 1").unwrap();
+    let blocked = Board::from_fen("4k3/3p4/8/3P4/8/8/8/4K3 w - - 0 1").unwrap();
+    assert!(evaluate(&passed) > evaluate(&blocked) + 100);
+}
+
+fn search_depth(fen: &str, depth: u32) -> (Board, website::chess::engine::SearchResult) {
+    let mut board = Board::from_fen(fen).unwrap();
+    let limits = SearchLimits {
+        depth: Some(depth),
+        ..Default::default()
+    };
+    let result = search(&mut board, &limits);
+    (board, result)
+}
+
+#[test]
+fn finds_mate_in_one() {
+    let (_, result) = search_depth("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", 3);
+    assert_eq!(format_move(&result.best_move.unwrap()), "a1a8");
+    assert_eq!(result.score, MATE_SCORE - 1);
+}
+
+#[test]
+fn finds_mate_in_two() {
+    let (_, result) = search_depth("k7/8/2K5/8/8/8/8/7R w - - 0 1", 4);
+    assert_eq!(result.score, MATE_SCORE - 3);
+    assert_eq!(result.pv.len(), 3);
+}
+
+#[test]
+fn checkmate_and_stalemate_have_no_best_move() {
+    let (_, mated) = search_depth("R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1", 3);
+    assert_eq!(mated.best_move, None);
+    assert_eq!(mated.score, -MATE_SCORE);
+    let (_, stalemate) = search_depth("k7/8/1Q6/8/8/8/8/7K b - - 0 1", 3);
+    assert_eq!(stalemate.best_move, None);
+    assert_eq!(stalemate.score, 0);
+}
+
+#[test]
+fn fifty_move_rule_draws_won_position() {
+    let (_, result) = search_depth("k7/8/8/8/8/8/8/KQ6 w - - 99 80", 2);
+    assert_eq!(result.score, 0);
+    let (_, result) = search_depth("k7/8/8/8/8/8/8/KQ6 w - - 0 80", 2);
+    assert!(result.score > 500);
+}
+
+#[test]
+fn mate_on_the_hundredth_ply_is_not_a_draw() {
+    let (_, result) = search_depth("6k1/5ppp/8/8/8/8/8/R5K1 w - - 99 80", 3);
+    assert_eq!(format_move(&result.best_move.unwrap()), "a1a8");
+    assert_eq!(result.score, MATE_SCORE - 1);
+}
+
+#[test]
+fn search_leaves_board_unchanged_and_pv_legal() {
+    let (mut board, result) = search_depth(KIWIPETE, 3);
+    assert_eq!(board.to_fen(), KIWIPETE);
+    assert_eq!(result.depth, 3);
+    assert_eq!(result.best_move, result.pv.first().copied());
+    for m in &result.pv {
+        assert!(board.generate_moves().contains(m), "{}", format_move(m));
+        board.make_move_mut(m);
+    }
+}
+
+#[test]
+fn wins_hanging_queen() {
+    let (_, result) = search_depth("4k3/8/8/3q4/8/8/3R4/4K3 w - - 0 1", 2);
+    assert_eq!(format_move(&result.best_move.unwrap()), "d2d5");
+}
+
+#[test]
+fn does_not_take_defended_pawn_at_the_horizon() {
+    // Qxd5 wins a pawn at depth 1, but exd5 loses the queen
+    let (_, result) = search_depth("4k3/8/4p3/3p4/8/8/8/3QK3 w - - 0 1", 1);
+    assert_ne!(format_move(&result.best_move.unwrap()), "d1d5");
+}
+
+#[test]
+fn mate_scores_convert_to_moves() {
+    assert_eq!(mate_in(MATE_SCORE - 1), Some(1));
+    assert_eq!(mate_in(MATE_SCORE - 3), Some(2));
+    assert_eq!(mate_in(-MATE_SCORE + 2), Some(-1));
+    assert_eq!(mate_in(-MATE_SCORE + 4), Some(-2));
+    assert_eq!(mate_in(250), None);
+}
@@ -0,0 +1,76 @@
+use website::chess::board::{Board, CreateBoardError, PositionError};
+use website::chess::utils::Color;
+
+#[test]
+fn round_trips() {
+    for fen in [
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "4k3/8/8/8/8/8/8/R3K3 b Q - 99 80",
+    ] {
+        assert_eq!(Board::from_fen(fen).unwrap().to_fen(), fen);
+    }
+}
+
+#[test]
+fn rejects_bad_fens() {
+    use CreateBoardError::*;
+    let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR";
+    for (fen, err) in [
+        (format!("{start} w KQkq - 0"), FenFieldCount(5)),
+        ("8/8/8/8 w - - 0 1".to_string(), RankCount(4)),
+        (
+            "rnbqkbnr/ppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string(),
+            RankLength { rank: 7, files: 7 },
+        ),
+        (
+            "rnbqkbnr/pppppppp/44/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string(),
+            ConsecutiveDigits { rank: 6 },
+        ),
+        (
+            "rnbqkbnr/ppppxppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1".to_string(),
+            InvalidPiece('x'),
+        ),
+        (
+            format!("{start} x KQkq - 0 1"),
+            InvalidSideToMove("x".to_string()),
+        ),
+        (
+            format!("{start} w KX - 0 1"),
+            InvalidCastlingRights("KX".to_string()),
+        ),
+        (
+            format!("{start} w KQkq e4 0 1"),
+            InvalidEnPassant("e4".to_string()),
+        ),
+        (
+            format!("{start} w KQkq - -1 1"),
+            InvalidHalfmoveClock("-1".to_string()),
+        ),
+        (
+            format!("{start} w KQkq - 0 0"),
+            InvalidFullmoveNumber("0".to_string()),
+        ),
+        (
+            "8/8/8/8/8/8/8/K7 w - - 0 1".to_string(),
+            InvalidPosition(PositionError::KingCount {
+                color: Color::Black,
+                count: 0,
+            }),
+        ),
+    ] {
+        assert_eq!(Board::from_fen(&fen).err(), Some(err), "{fen}");
+    }
+}
+
+#[test]
+fn rejects_short_piece_lists() {
+    assert_eq!(
+        Board::from_piece_list(&[None; 63]).err(),
+        Some(CreateBoardError::PieceListLength(63))
+    );
+}
@@ -0,0 +1,179 @@
+mod common;
+
+use common::Server;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Returns an empty directory for a test's games
+fn games_dir(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("website-games-{test}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Returns the body of a request to play `m` with `token`
+fn play(m: &str, ply: Option<usize>, token: &Value) -> String {
+    json!({"move": m, "ply": ply, "token": token}).to_string()
+}
+
+#[test]
+fn plays_and_saves_moves() {
+    let dir = games_dir("plays");
+    let server = Server::start(&[("GAMES_DIR", dir.to_str().unwrap())]);
+    let (status, game) = server.json("POST", "/api/chess/games", "");
+    assert_eq!(status, 200);
+    let id = game["id"].as_str().unwrap();
+    assert_eq!(id.len(), 16);
+    assert_eq!(game["moves"], json!([]));
+    assert_eq!(game["side"], "w");
+    let (_, black) = server.json("POST", &format!("/api/chess/games/{id}/join"), "");
+    assert_eq!(black["side"], "b");
+    let tokens = [&game["token"], &black["token"]];
+
+    let moves = format!("/api/chess/games/{id}/moves");
+    for (ply, m) in ["e4", "e7e5", "Qh5", "Nc6", "Bc4", "Nf6", "Qxf7"]
+        .into_iter()
+        .enumerate()
+    {
+        let (status, _) = server.json("POST", &moves, &play(m, None, tokens[ply % 2]));
+        assert_eq!(status, 200);
+    }
+    let (_, game) = server.json("GET", &format!("/api/chess/games/{id}"), "");
+    assert_eq!(game["moves"][6]["san"], "Qxf7#");
+    assert_eq!(game["status"], "checkmate");
+
+    let (status, body) = server.json("POST", &moves, &play("Ke7", None, tokens[1]));
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "the game is over");
+
+    let (status, pgn) = server.request("GET", &format!("/api/chess/games/{id}/pgn"), "");
+    assert_eq!(status, 200);
+    assert!(
+        pgn.contains("1. e4 e5 2. Qh5 Nc6 3. Bc4 Nf6 4. Qxf7# 1-0"),
+        "{pgn}"
+    );
+
+    // The game outlives the server
+    drop(server);
+    let server = Server::start(&[("GAMES_DIR", dir.to_str().unwrap())]);
+    let (status, game) = server.json("GET", &format!("/api/chess/games/{id}"), "");
+    assert_eq!(status, 200);
+    assert_eq!(game["moves"].as_array().unwrap().len(), 7);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn refuses_moves_to_a_game_that_moved_on() {
+    let dir = games_dir("stale");
+    let server = Server::start(&[("GAMES_DIR", dir.to_str().unwrap())]);
+    let (_, white) = server.json("POST", "/api/chess/games", "");
+    let id = white["id"].as_str().unwrap();
+    let (_, black) = server.json("POST", &format!("/api/chess/games/{id}/join"), "");
+    let moves = format!("/api/chess/games/{id}/moves");
+
+    let (status, body) = server.json("POST", &moves, &play("e2e5", None, &white["token"]));
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "illegal move 'e2e5'");
+    let (status, _) = server.json("POST", &moves, &play("d4", Some(0), &white["token"]));
+    assert_eq!(status, 200);
+    // White already played the first move from another tab
+    let (status, _) = server.json("POST", &moves, &play("e4", Some(0), &white["token"]));
+    assert_eq!(status, 403);
+    let (status, _) = server.json("POST", &moves, &play("e5", Some(0), &black["token"]));
+    assert_eq!(status, 409);
+    let (status, game) = server.json("POST", &moves, &play("d5", Some(1), &black["token"]));
+    assert_eq!(status, 200);
+    assert_eq!(game["moves"][1]["uci"], "d7d5");
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn players_only_move_their_own_side() {
+    let dir = games_dir("sides");
+    let server = Server::start(&[("GAMES_DIR", dir.to_str().unwrap())]);
+    let (_, white) = server.json("POST", "/api/chess/games", "");
+    let id = white["id"].as_str().unwrap();
+    let join = format!("/api/chess/games/{id}/join");
+    let moves = format!("/api/chess/games/{id}/moves");
+
+    // Nobody can play black before someone joins as black
+    let (status, _) = server.json("POST", &moves, &play("e4", None, &white["token"]));
+    assert_eq!(status, 200);
+    let (status, body) = server.json("POST", &moves, &play("e5", None, &white["token"]));
+    assert_eq!(status, 403);
+    assert_eq!(body["error"], "it isn't your move");
+
+    let (status, black) = server.json("POST", &join, "");
+    assert_eq!(status, 200);
+    assert_eq!(black["side"], "b");
+    assert_eq!(black["moves"][0]["san"], "e4");
+    assert_ne!(black["token"], white["token"]);
+    let (status, _) = server.json("POST", &join, "");
+    assert_eq!(status, 403);
+
+    for body in [
+        r#"{"move": "e5"}"#.to_string(),
+        play("e5", None, &json!("0123456789abcdef0123456789abcdef")),
+        play("e5", None, &white["token"]),
+    ] {
+        let (status, _) = server.json("POST", &moves, &body);
+        assert_eq!(status, 403, "{body}");
+    }
+    let (status, _) = server.json("POST", &moves, &play("e5", None, &black["token"]));
+    assert_eq!(status, 200);
+    let (status, _) = server.json("POST", &moves, &play("Nf3", None, &black["token"]));
+    assert_eq!(status, 403);
+
+    // The tokens are never shown to anyone else
+    let (_, game) = server.json("GET", &format!("/api/chess/games/{id}"), "");
+    assert!(game.get("token").is_none());
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn players_resume_with_their_token() {
+    let dir = games_dir("resume");
+    let server = Server::start(&[("GAMES_DIR", dir.to_str().unwrap())]);
+    let (_, white) = server.json("POST", "/api/chess/games", "");
+    let game = format!("/api/chess/games/{}", white["id"].as_str().unwrap());
+    let (_, black) = server.json("POST", &format!("{game}/join"), "");
+
+    // The token alone says which side to play, from any browser
+    for (player, side) in [(&white, "w"), (&black, "b")] {
+        let token = player["token"].as_str().unwrap();
+        let (status, resumed) = server.json("GET", &format!("{game}?token={token}"), "");
+        assert_eq!(status, 200);
+        assert_eq!(resumed["side"], side);
+    }
+    for path in [format!("{game}?token=nonsense"), game.clone()] {
+        let (_, watched) = server.json("GET", &path, "");
+        assert!(watched.get("side").is_none(), "{path}");
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn unknown_games_are_not_found() {
+    let dir = games_dir("unknown");
+    let server = Server::start(&[("GAMES_DIR", dir.to_str().unwrap())]);
+    let (status, _) = server.json("GET", "/api/chess/games/0123456789abcdef", "");
+    assert_eq!(status, 404);
+    let (status, _) = server.json("GET", "/api/chess/games/..%2F..%2Fetc%2Fpasswd", "");
+    assert_eq!(status, 404);
+
+    // The page itself is served for any ID, and says so when it loads
+    let (status, page) = server.request("GET", "/chess/game/0123456789abcdef", "");
+    assert_eq!(status, 200);
+    assert!(page.contains("/js/game.js"));
+}
+
+#[test]
+fn games_that_cant_be_saved_are_errors() {
+    // The games directory can't be created where a file is
+    let file = games_dir("unsaved");
+    std::fs::write(&file, "").unwrap();
+    let server = Server::start(&[("GAMES_DIR", file.to_str().unwrap())]);
+    let (status, _) = server.json("POST", "/api/chess/games", "");
+    assert_eq!(status, 500);
+    let _ = std::fs::remove_file(&file);
+}
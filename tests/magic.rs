@@ -0,0 +1,57 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use website::chess::bitboard::Bitboard;
+use website::chess::magic;
+use website::chess::utils::Square;
+
+/// Returns the attacks of a slider on `s` moving in steps of `(file, rank)`,
+/// walking each ray a square at a time until it leaves the board or hits a
+/// piece in `occupied`
+fn walk(s: Square, steps: &[(i8, i8)], occupied: u64) -> Bitboard {
+    let mut attacks = 0;
+    for &(df, dr) in steps {
+        let (mut file, mut rank) = (s.file() as i8, s.rank() as i8);
+        loop {
+            file += df;
+            rank += dr;
+            if !(0..8).contains(&file) || !(0..8).contains(&rank) {
+                break;
+            }
+            let bit = 1 << (rank * 8 + file);
+            attacks |= bit;
+            if occupied & bit != 0 {
+                break;
+            }
+        }
+    }
+    Bitboard(attacks)
+}
+
+#[test]
+fn magic_attacks_match_ray_walks() {
+    let rook = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+    let bishop = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+    let mut rng = StdRng::seed_from_u64(0x6d_6167_6963);
+    for i in 0..64 {
+        let s = Square::from_file_rank(i % 8, i / 8).unwrap();
+        for _ in 0..1000 {
+            // Sparse boards as well as crowded ones, so that long rays get
+            // checked too
+            let occupied = match rng.gen_range(0..3) {
+                0 => rng.gen::<u64>(),
+                1 => rng.gen::<u64>() & rng.gen::<u64>(),
+                _ => rng.gen::<u64>() & rng.gen::<u64>() & rng.gen::<u64>(),
+            };
+            assert_eq!(
+                magic::rook_attacks(s, Bitboard(occupied)),
+                walk(s, &rook, occupied),
+                "rook on {s} with occupancy {occupied:#x}"
+            );
+            assert_eq!(
+                magic::bishop_attacks(s, Bitboard(occupied)),
+                walk(s, &bishop, occupied),
+                "bishop on {s} with occupancy {occupied:#x}"
+            );
+        }
+    }
+}
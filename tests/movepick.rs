@@ -0,0 +1,75 @@
+use website::chess::board::Board;
+use website::chess::cmove::CMove;
+use website::chess::movepick::{History, Killers, MovePicker};
+use website::chess::utils::{Piece, Square};
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+fn find(board: &Board, uci: &str) -> CMove {
+    board
+        .generate_moves()
+        .into_iter()
+        .find(|m| format_move(m) == uci)
+        .unwrap()
+}
+
+/// Returns `m` in long algebraic notation, e.g. `e2e4` or `e7e8q`
+fn format_move(m: &CMove) -> String {
+    let square = |s: Square| format!("{}{}", (b'a' + s.file()) as char, s.rank() + 1);
+    let promo = match m.is_promo() {
+        Some(Piece::Knight) => "n",
+        Some(Piece::Bishop) => "b",
+        Some(Piece::Rook) => "r",
+        Some(Piece::Queen) => "q",
+        _ => "",
+    };
+    format!("{}{}{promo}", square(m.get_from()), square(m.get_to()))
+}
+
+#[test]
+fn picks_every_move_once() {
+    let board = Board::from_fen(KIWIPETE).unwrap();
+    let mut moves = board.generate_moves();
+    let picker = MovePicker::new(&board, moves.clone(), None, [None; 2], &History::default());
+    let mut picked: Vec<CMove> = picker.collect();
+    let key = |m: &CMove| m.to_bits();
+    moves.sort_by_key(key);
+    picked.sort_by_key(key);
+    assert_eq!(moves, picked);
+}
+
+#[test]
+fn picks_in_stages() {
+    let board = Board::from_fen(KIWIPETE).unwrap();
+    let hash_move = find(&board, "a2a3");
+    let killer = find(&board, "g2g3");
+    let good_quiet = find(&board, "e1d1");
+
+    let mut killers = Killers::default();
+    killers.store(3, killer);
+    let mut history = History::default();
+    history.update(board.side_to_move(), good_quiet, 5);
+
+    let picked: Vec<String> = MovePicker::new(
+        &board,
+        board.generate_moves(),
+        Some(hash_move),
+        killers.get(3),
+        &history,
+    )
+    .map(|m| format_move(&m))
+    .collect();
+
+    assert_eq!(picked[0], "a2a3");
+    // Bishop takes bishop is the most valuable victim for the least valuable
+    // attacker, and queen takes pawn the least
+    let captures = board
+        .generate_moves()
+        .iter()
+        .filter(|m| m.is_capture())
+        .count();
+    assert_eq!(picked[1], "e2a6");
+    assert_eq!(picked[captures], "f3h3");
+    assert_eq!(picked[captures + 1], "g2g3");
+    assert_eq!(picked[captures + 2], "e1d1");
+}
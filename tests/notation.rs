@@ -0,0 +1,161 @@
+use website::chess::board::{Board, ParseMoveError};
+use website::chess::utils::{Piece, Square};
+
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+
+#[test]
+fn squares_and_pieces_display() {
+    assert_eq!(Square::A1.to_string(), "a1");
+    assert_eq!(Square::E4.to_string(), "e4");
+    assert_eq!(Square::H8.to_string(), "h8");
+    assert_eq!(Square::from_algebraic("e4"), Some(Square::E4));
+    assert_eq!(Square::from_algebraic("i1"), None);
+    assert_eq!(Square::from_algebraic("e"), None);
+    assert_eq!(Piece::Knight.to_string(), "N");
+    assert_eq!(Piece::Pawn.to_string(), "P");
+}
+
+#[test]
+fn every_legal_move_round_trips() {
+    for fen in [
+        KIWIPETE,
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+    ] {
+        let board = Board::from_fen(fen).unwrap();
+        for m in board.generate_moves() {
+            assert_eq!(board.parse_uci_move(&m.to_uci()), Ok(m), "{fen}");
+        }
+    }
+}
+
+#[test]
+fn flags_come_from_the_position() {
+    let board = Board::from_fen(KIWIPETE).unwrap();
+    assert!(board.parse_uci_move("e1g1").unwrap().is_king_castle());
+    assert!(board.parse_uci_move("e1c1").unwrap().is_queen_castle());
+    assert!(board.parse_uci_move("a2a4").unwrap().is_pawn_dpush());
+    assert!(board.parse_uci_move("e2a6").unwrap().is_capture());
+    assert!(!board.parse_uci_move("e2d3").unwrap().is_capture());
+
+    let board =
+        Board::from_fen("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3").unwrap();
+    assert!(board.parse_uci_move("e5f6").unwrap().is_ep_capture());
+
+    let board = Board::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    let m = board.parse_uci_move("a7b8n").unwrap();
+    assert!(m.is_capture());
+    assert_eq!(m.is_promo(), Some(Piece::Knight));
+    assert_eq!(m.to_uci(), "a7b8n");
+}
+
+#[test]
+fn bad_moves_are_rejected() {
+    let board = Board::new();
+    for bad in ["", "e2", "e2e", "e2e9", "z2e4", "e2e4x", "e7e8k", "e2e4qq"] {
+        assert_eq!(
+            board.parse_uci_move(bad),
+            Err(ParseMoveError::InvalidNotation(bad.to_string()))
+        );
+    }
+    for illegal in ["e2e5", "e7e5", "e1g1", "a2a1q"] {
+        assert_eq!(
+            board.parse_uci_move(illegal),
+            Err(ParseMoveError::IllegalMove(illegal.to_string()))
+        );
+    }
+}
+
+/// Returns the SAN of move `uci` in `fen`
+fn san(fen: &str, uci: &str) -> String {
+    let board = Board::from_fen(fen).unwrap();
+    board.to_san(board.parse_uci_move(uci).unwrap())
+}
+
+#[test]
+fn writes_san() {
+    let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+    assert_eq!(san(start, "e2e4"), "e4");
+    assert_eq!(san(start, "g1f3"), "Nf3");
+    assert_eq!(san(KIWIPETE, "e1g1"), "O-O");
+    assert_eq!(san(KIWIPETE, "e1c1"), "O-O-O");
+    assert_eq!(san(KIWIPETE, "d5e6"), "dxe6");
+    assert_eq!(san(KIWIPETE, "e2a6"), "Bxa6");
+    // Disambiguation by file, then rank, then both
+    let board = "3k4/8/8/8/8/8/4K3/R6R w - - 0 1";
+    assert_eq!(san(board, "a1d1"), "Rad1+");
+    assert_eq!(san(board, "h1f1"), "Rhf1");
+    assert_eq!(san("4k3/8/8/8/R7/8/8/R3K3 w - - 0 1", "a1a2"), "R1a2");
+    assert_eq!(san("4k3/8/8/8/8/8/8/N1N1K3 w - - 0 1", "a1b3"), "Nab3");
+    assert_eq!(san("4k3/8/8/8/8/Q1Q5/8/Q3K3 w - - 0 1", "a3b2"), "Qa3b2");
+    assert_eq!(san("4k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7a8q"), "a8=Q+");
+    assert_eq!(san("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7b8n"), "axb8=N");
+    assert_eq!(
+        san(
+            "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+            "e5f6"
+        ),
+        "exf6"
+    );
+    assert_eq!(san("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", "a1a8"), "Ra8#");
+}
+
+#[test]
+fn every_legal_move_round_trips_through_san() {
+    for fen in [
+        KIWIPETE,
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "4k3/8/8/8/8/Q1Q5/8/Q3K3 w - - 0 1",
+        "n1n1k3/8/8/8/8/8/8/4K3 b - - 0 1",
+    ] {
+        let board = Board::from_fen(fen).unwrap();
+        for m in board.generate_moves() {
+            assert_eq!(board.parse_san(&board.to_san(m)), Ok(m), "{fen}");
+        }
+    }
+}
+
+#[test]
+fn reads_san_leniently() {
+    let board = Board::from_fen(KIWIPETE).unwrap();
+    for (san, uci) in [
+        ("0-0", "e1g1"),
+        ("O-O-O", "e1c1"),
+        ("Bxa6!?", "e2a6"),
+        ("dxe6", "d5e6"),
+        ("Nxf7", "e5f7"),
+        ("Ng6+", "e5g6"),
+        ("a3", "a2a3"),
+    ] {
+        assert_eq!(
+            board.to_san(board.parse_san(san).unwrap()),
+            board.to_san(board.parse_uci_move(uci).unwrap())
+        );
+    }
+    let board = Board::from_fen("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    assert_eq!(board.parse_san("axb8Q").unwrap().to_uci(), "a7b8q");
+}
+
+#[test]
+fn bad_san_is_rejected() {
+    let board = Board::from_fen("4k3/8/8/8/8/8/8/N1N1K3 w - - 0 1").unwrap();
+    assert_eq!(
+        board.parse_san("Nb3"),
+        Err(ParseMoveError::AmbiguousMove("Nb3".to_string()))
+    );
+    assert_eq!(
+        board.parse_san("Nd4"),
+        Err(ParseMoveError::IllegalMove("Nd4".to_string()))
+    );
+    assert_eq!(
+        board.parse_san("O-O"),
+        Err(ParseMoveError::IllegalMove("O-O".to_string()))
+    );
+    for bad in ["", "N", "Nz3", "e9", "Nb3b", "a8=K", "Xe4"] {
+        assert_eq!(
+            board.parse_san(bad),
+            Err(ParseMoveError::InvalidNotation(bad.to_string())),
+            "{bad}"
+        );
+    }
+}
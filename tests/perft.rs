@@ -0,0 +1,81 @@
+//! Perft node counts for the standard test positions from
+//! <https://www.chessprogramming.org/Perft_Results>
+
+use website::chess::board::Board;
+
+const STARTPOS: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+const KIWIPETE: &str = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+const POSITION3: &str = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+const POSITION4: &str = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+const POSITION5: &str = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+const POSITION6: &str = "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10";
+
+/// Checks the perft counts of `fen` for depths 1, 2, ... and that the
+/// position is unchanged afterwards
+fn check_perft(fen: &str, counts: &[u64]) {
+    let mut board = Board::from_fen(fen).unwrap();
+    for (depth, &count) in (1..).zip(counts) {
+        assert_eq!(board.perft(depth), count, "{fen} at depth {depth}");
+    }
+    assert_eq!(board.to_fen(), fen);
+}
+
+#[test]
+fn startpos() {
+    assert_eq!(Board::new().to_fen(), STARTPOS);
+    check_perft(STARTPOS, &[20, 400, 8902, 197281]);
+}
+
+#[test]
+fn kiwipete() {
+    check_perft(KIWIPETE, &[48, 2039, 97862]);
+}
+
+#[test]
+fn position3() {
+    check_perft(POSITION3, &[14, 191, 2812, 43238]);
+}
+
+#[test]
+fn position4() {
+    check_perft(POSITION4, &[6, 264, 9467]);
+}
+
+#[test]
+fn position4_mirrored() {
+    check_perft(
+        "r2q1rk1/pP1p2pp/Q4n2/bbp1p3/Np6/1B3NBn/pPPP1PPP/R3K2R b KQ - 0 1",
+        &[6, 264, 9467],
+    );
+}
+
+#[test]
+fn position5() {
+    check_perft(POSITION5, &[44, 1486, 62379]);
+}
+
+#[test]
+fn position6() {
+    check_perft(POSITION6, &[46, 2079, 89890]);
+}
+
+#[test]
+fn divide_sums_to_perft() {
+    let mut board = Board::from_fen(KIWIPETE).unwrap();
+    let divide = board.divide(3);
+    assert_eq!(divide.len(), 48);
+    assert_eq!(divide.iter().map(|(_, nodes)| nodes).sum::<u64>(), 97862);
+}
+
+/// Deeper counts, too slow for debug builds. Run with
+/// `cargo test --release -- --ignored`
+#[test]
+#[ignore]
+fn deep() {
+    check_perft(STARTPOS, &[20, 400, 8902, 197281, 4865609]);
+    check_perft(KIWIPETE, &[48, 2039, 97862, 4085603]);
+    check_perft(POSITION3, &[14, 191, 2812, 43238, 674624, 11030083]);
+    check_perft(POSITION4, &[6, 264, 9467, 422333, 15833292]);
+    check_perft(POSITION5, &[44, 1486, 62379, 2103487]);
+    check_perft(POSITION6, &[46, 2079, 89890, 3894594]);
+}
@@ -0,0 +1,164 @@
+use website::chess::board::{Board, ParseMoveError};
+use website::chess::pgn::{self, Game, PgnError};
+
+const GAMES: &str = r#"[Event "F/S Return Match"]
+[Site "Belgrade, Serbia JUG"]
+[Date "1992.11.04"]
+[Round "29"]
+[White "Fischer, Robert J."]
+[Black "Spassky, Boris V."]
+[Result "1/2-1/2"]
+[ECO "C95"]
+
+1. e4 e5 2. Nf3 Nc6 3. Bb5 {This opening is called the Ruy Lopez.} 3... a6
+4. Ba4 Nf6 5. O-O Be7 6. Re1 b5 7. Bb3 d6 8. c3 O-O 9. h3 Nb8 10. d4 Nbd7
+11. c4 c6 12. cxb5 axb5 13. Nc3 Bb7 14. Bg5 b4 15. Nb1 h6 16. Bh4 c5 17. dxe5
+Nxe4 18. Bxe7 Qxe7 19. exd6 Qf6 20. Nbd2 Nxd6 21. Nc4 Nxc4 22. Bxc4 Nb6
+23. Ne5 Rae8 24. Bxf7+ Rxf7 25. Nxf7 Rxe1+ 26. Qxe1 Kxf7 27. Qe3 Qg5 28. Qxg5
+hxg5 29. b3 Ke6 30. a3 Kd6 31. axb4 cxb4 32. Ra5 Nd5 33. f3 Bc8 34. Kf2 Bf5
+35. Ra7 g6 36. Ra6+ Kc5 37. Ke1 Nf4 38. g3 Nxh3 39. Kd2 Kb5 40. Rd6 Kc5 41. Ra6
+Nf2 42. g4 Bd3 43. Re6 1/2-1/2
+
+[Event "Casual"]
+[White "A"]
+[Black "B"]
+[Result "*"]
+
+1. e4 $1 (1. d4 d5 (1... Nf6 2. c4) 2. c4) ; the king's pawn
+1... e5!? {A classical reply} 2. Nf3 *
+"#;
+
+#[test]
+fn parses_every_game() {
+    let games = pgn::parse(GAMES).unwrap();
+    assert_eq!(games.len(), 2);
+
+    let fischer = &games[0];
+    assert_eq!(fischer.tag("White"), Some("Fischer, Robert J."));
+    assert_eq!(fischer.tag("ECO"), Some("C95"));
+    assert_eq!(fischer.result, "1/2-1/2");
+    assert_eq!(fischer.moves.len(), 85);
+    assert_eq!(fischer.moves[4].san, "Bb5");
+    assert_eq!(
+        fischer.moves[4].comments,
+        ["This opening is called the Ruy Lopez."]
+    );
+    assert_eq!(
+        fischer.board().unwrap().to_fen(),
+        "8/8/4R1p1/2k3p1/1p4P1/1P1b1P2/3K1n2/8 b - - 2 43"
+    );
+}
+
+#[test]
+fn reads_annotations_and_variations() {
+    let game = &pgn::parse(GAMES).unwrap()[1];
+    assert_eq!(game.result, "*");
+    assert_eq!(game.moves.len(), 3);
+
+    let e4 = &game.moves[0];
+    assert_eq!(e4.nags, [1]);
+    assert_eq!(e4.comments, ["the king's pawn"]);
+    assert_eq!(e4.variations.len(), 1);
+
+    // Variations are replayed from the position before the move they replace
+    let d4 = &e4.variations[0];
+    let sans: Vec<&str> = d4.iter().map(|m| m.san.as_str()).collect();
+    assert_eq!(sans, ["d4", "d5", "c4"]);
+    let nested: Vec<&str> = d4[1].variations[0].iter().map(|m| m.san.as_str()).collect();
+    assert_eq!(nested, ["Nf6", "c4"]);
+
+    let e5 = &game.moves[1];
+    assert_eq!(e5.san, "e5");
+    assert_eq!(e5.nags, [5]);
+    assert_eq!(e5.comments, ["A classical reply"]);
+}
+
+#[test]
+fn reports_illegal_moves_with_line_and_ply() {
+    let pgn = "[Event \"?\"]\n\n1. e4 e5 2. Nf3 Nc6\n3. Bb5 Ke7 4. Ke3 *\n";
+    assert_eq!(
+        pgn::parse(pgn),
+        Err(PgnError::IllegalMove {
+            line: 4,
+            ply: 7,
+            san: "Ke3".to_string(),
+            err: ParseMoveError::IllegalMove("Ke3".to_string()),
+        })
+    );
+
+    // Including moves in variations
+    let pgn = "1. e4 e5\n(1... Nf6 2. Nf6) *";
+    assert!(matches!(
+        pgn::parse(pgn),
+        Err(PgnError::IllegalMove {
+            line: 2,
+            ply: 3,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn rejects_malformed_text() {
+    assert!(matches!(
+        pgn::parse("1. e4 {unterminated\n\ncomment"),
+        Err(PgnError::Syntax { line: 1, .. })
+    ));
+    assert!(matches!(
+        pgn::parse("1. e4 (1. d4 *"),
+        Err(PgnError::Syntax { .. })
+    ));
+    assert!(matches!(
+        pgn::parse("[FEN \"not a fen\"]\n1. e4 *"),
+        Err(PgnError::InvalidFen { line: 1, .. })
+    ));
+}
+
+#[test]
+fn starts_from_fen_tag() {
+    let pgn = "[SetUp \"1\"]\n[FEN \"k7/8/1K6/8/8/8/8/7R w - - 0 1\"]\n\n1. Rh8# 1-0";
+    let game = &pgn::parse(pgn).unwrap()[0];
+    assert_eq!(game.moves[0].san, "Rh8#");
+    assert!(game.board().unwrap().generate_moves().is_empty());
+}
+
+#[test]
+fn export_reads_back_the_same() {
+    for game in pgn::parse(GAMES).unwrap() {
+        let exported = game.to_pgn();
+        assert!(exported.lines().all(|line| line.len() < 80));
+        let read = pgn::parse(&exported).unwrap().remove(0);
+        assert_eq!(read.moves, game.moves);
+        assert_eq!(read.result, game.result);
+        assert_eq!(read.to_pgn(), exported);
+    }
+
+    let game = &pgn::parse(GAMES).unwrap()[1];
+    assert!(game
+        .to_pgn()
+        .starts_with("[Event \"Casual\"]\n[Site \"?\"]\n[Date \"????.??.??\"]"));
+    assert!(game
+        .to_pgn()
+        .contains("1. e4 $1 {the king's pawn} (1. d4 d5 (1... Nf6 2. c4) 2. c4) 1... e5 $5"));
+}
+
+#[test]
+fn exports_played_game() {
+    let mut board = Board::new();
+    for san in ["f3", "e5", "g4", "Qh4"] {
+        let m = board.parse_san(san).unwrap();
+        board.make_move_mut(&m);
+    }
+    let game = Game::from_board(&board);
+    assert_eq!(game.result, "0-1");
+    assert_eq!(game.tag("Result"), Some("0-1"));
+    assert!(game.to_pgn().ends_with("\n1. f3 e5 2. g4 Qh4# 0-1\n"));
+
+    let mut board = Board::from_fen("k7/8/2K5/8/8/8/8/7R b - - 3 40").unwrap();
+    let m = board.parse_san("Kb8").unwrap();
+    board.make_move_mut(&m);
+    let game = Game::from_board(&board);
+    assert_eq!(game.tag("SetUp"), Some("1"));
+    assert!(game.to_pgn().ends_with("\n40... Kb8 *\n"));
+    assert_eq!(pgn::parse(&game.to_pgn()).unwrap(), [game]);
+}
@@ -0,0 +1,78 @@
+use website::chess::board::Board;
+use website::chess::cmove::CMove;
+use website::chess::utils::{Piece, Square};
+
+/// Returns the static exchange evaluation of move `uci` in `fen`
+fn see(fen: &str, uci: &str) -> i32 {
+    let board = Board::from_fen(fen).unwrap();
+    let m = board
+        .generate_moves()
+        .into_iter()
+        .find(|m| format_move(m) == uci)
+        .unwrap();
+    board.see(m)
+}
+
+/// Returns `m` in long algebraic notation, e.g. `e2e4` or `e7e8q`
+fn format_move(m: &CMove) -> String {
+    let square = |s: Square| format!("{}{}", (b'a' + s.file()) as char, s.rank() + 1);
+    let promo = match m.is_promo() {
+        Some(Piece::Knight) => "n",
+        Some(Piece::Bishop) => "b",
+        Some(Piece::Rook) => "r",
+        Some(Piece::Queen) => "q",
+        _ => "",
+    };
+    format!("{}{}{promo}", square(m.get_from()), square(m.get_to()))
+}
+
+#[test]
+fn undefended_pawn() {
+    assert_eq!(
+        see("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", "e1e5"),
+        100
+    );
+}
+
+#[test]
+fn knight_for_defended_pawn() {
+    assert_eq!(
+        see(
+            "1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1",
+            "d3e5"
+        ),
+        -200
+    );
+}
+
+#[test]
+fn quiet_moves_and_equal_trades() {
+    // Knight to a square defended by a pawn
+    assert_eq!(see("4k3/8/2p5/8/8/2N5/8/4K3 w - - 0 1", "c3d5"), -300);
+    // Queen takes queen, then the king recaptures when it can
+    assert_eq!(see("k7/4q3/8/8/8/8/4Q3/4K3 w - - 0 1", "e2e7"), 900);
+    assert_eq!(see("3k4/4q3/8/8/8/8/4Q3/4K3 w - - 0 1", "e2e7"), 0);
+}
+
+#[test]
+fn batteries_join_in() {
+    // Rook takes a defended rook, with the second rook behind it winning the exchange
+    assert_eq!(see("3rk3/3r4/8/8/8/8/3R4/3RK3 w - - 0 1", "d2d7"), 0);
+    assert_eq!(see("4k3/3r4/8/8/8/8/3R4/3RK3 w - - 0 1", "d2d7"), 500);
+    // The queen behind the bishop makes taking the pawn safe
+    assert_eq!(see("4k3/8/2b5/8/4p3/8/6B1/K6Q w - - 0 1", "g2e4"), 100);
+    assert_eq!(see("4k3/8/2b5/8/4p3/8/6B1/K7 w - - 0 1", "g2e4"), -200);
+}
+
+#[test]
+fn king_cannot_recapture_defended_piece() {
+    assert_eq!(see("8/8/8/8/3k4/3p4/3R4/3RK3 w - - 0 1", "d2d3"), 100);
+    assert_eq!(see("8/8/8/8/8/3k4/3q4/3RK3 w - - 0 1", "d1d2"), 900);
+}
+
+#[test]
+fn en_passant_and_promotion() {
+    assert_eq!(see("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "e5d6"), 100);
+    assert_eq!(see("1r2k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7b8q"), 1300);
+    assert_eq!(see("1rr1k3/P7/8/8/8/8/8/4K3 w - - 0 1", "a7b8q"), 400);
+}
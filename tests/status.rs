@@ -0,0 +1,93 @@
+use website::chess::board::{Board, Status};
+
+fn play(board: &mut Board, sans: &[&str]) {
+    for san in sans {
+        let m = board.parse_san(san).unwrap();
+        board.make_move_mut(&m);
+    }
+}
+
+#[test]
+fn mate_and_stalemate() {
+    let mut board = Board::new();
+    assert_eq!(board.status(), Status::Ongoing);
+    play(&mut board, &["f3", "e5", "g4", "Qh4"]);
+    assert_eq!(board.status(), Status::Checkmate);
+
+    let board = Board::from_fen("k7/8/1Q6/8/8/8/8/7K b - - 0 1").unwrap();
+    assert_eq!(board.status(), Status::Stalemate);
+}
+
+#[test]
+fn fifty_move_rule_takes_100_plies() {
+    let fen = |clock| format!("4k3/8/8/8/8/8/8/R3K3 w - - {clock} 80");
+    let board = Board::from_fen(&fen(50)).unwrap();
+    assert!(!board.generate_moves().is_empty());
+    assert_eq!(board.status(), Status::Ongoing);
+
+    let mut board = Board::from_fen(&fen(99)).unwrap();
+    assert_eq!(board.status(), Status::Ongoing);
+    play(&mut board, &["Ra2"]);
+    assert_eq!(board.status(), Status::DrawFiftyMove);
+
+    // Unless the 100th ply mates
+    let mut board = Board::from_fen("4k3/R7/4K3/8/8/8/8/8 w - - 99 80").unwrap();
+    play(&mut board, &["Ra8"]);
+    assert_eq!(board.status(), Status::Checkmate);
+}
+
+#[test]
+fn third_repetition_draws() {
+    let mut board = Board::new();
+    let shuffle = ["Nf3", "Nf6", "Ng1", "Ng8"];
+    play(&mut board, &shuffle);
+    assert_eq!(board.repetitions(), 1);
+    assert_eq!(board.status(), Status::Ongoing);
+    play(&mut board, &shuffle);
+    assert_eq!(board.repetitions(), 2);
+    assert_eq!(board.status(), Status::DrawRepetition);
+
+    // A pawn move makes every earlier position unreachable
+    play(&mut board, &["e3", "e6"]);
+    play(&mut board, &shuffle);
+    assert_eq!(board.repetitions(), 1);
+
+    // Unmaking moves walks back through the history
+    for _ in 0..4 {
+        board.unmake_move();
+    }
+    assert_eq!(board.repetitions(), 0);
+}
+
+#[test]
+fn double_pushes_no_pawn_can_take_still_repeat() {
+    let mut board = Board::new();
+    play(&mut board, &["e4", "e5"]);
+    for _ in 0..2 {
+        play(&mut board, &["Nf3", "Nf6", "Ng1", "Ng8"]);
+    }
+    assert_eq!(board.status(), Status::DrawRepetition);
+}
+
+#[test]
+fn insufficient_material() {
+    for (fen, insufficient) in [
+        ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", true),
+        ("4k3/8/8/8/8/8/8/2N1K3 w - - 0 1", true),
+        ("4k3/8/8/8/8/8/8/2B1K3 b - - 0 1", true),
+        // Bishops on the same color can never cover a king's escape squares
+        ("2b1k3/8/8/8/8/8/8/4KB2 w - - 0 1", true),
+        ("1b2k3/8/8/8/8/8/8/4KB2 w - - 0 1", false),
+        ("4k3/8/8/8/8/8/8/1NN1K3 w - - 0 1", false),
+        ("4k3/8/8/8/8/8/8/2BNK3 w - - 0 1", false),
+        ("4k3/8/8/8/8/8/P7/4K3 w - - 0 1", false),
+    ] {
+        let board = Board::from_fen(fen).unwrap();
+        assert_eq!(board.insufficient_material(), insufficient, "{fen}");
+        let status = match insufficient {
+            true => Status::DrawInsufficientMaterial,
+            false => Status::Ongoing,
+        };
+        assert_eq!(board.status(), status, "{fen}");
+    }
+}
@@ -0,0 +1,156 @@
+//! Checks the generated attack tables against the hand-written tables they
+//! replaced, and against tables built the slow way by stepping across files
+//! and ranks
+
+use website::chess::bitboard::Bitboard;
+use website::chess::board::Board;
+use website::chess::magic;
+use website::chess::utils::{Color, Dir, Square};
+
+// Fingerprints of the hand-written tables, taken before they were replaced
+const LEGACY_PAWN_ATTACKS: u64 = 0xcfa2_f897_a112_d7c6;
+const LEGACY_KING_ATTACKS: u64 = 0xffed_7993_989a_e3c2;
+const LEGACY_KNIGHT_ATTACKS: u64 = 0x4aa3_570c_a1c6_0201;
+const LEGACY_RAY_ATTACKS: u64 = 0x887f_8dc2_38ec_dace;
+const LEGACY_IN_BETWEEN: u64 = 0xc467_8bb1_eb31_9b55;
+
+const DIRS: [Dir; 8] = [
+    Dir::Nort,
+    Dir::Noea,
+    Dir::East,
+    Dir::Soea,
+    Dir::Sout,
+    Dir::Sowe,
+    Dir::West,
+    Dir::Nowe,
+];
+
+const KING_STEPS: [(i8, i8); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+const KNIGHT_STEPS: [(i8, i8); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+/// Returns an FNV-1a hash of a table's entries, in the order they are
+/// indexed
+fn fingerprint(table: &[Bitboard]) -> u64 {
+    table.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ b.0).wrapping_mul(0x100_0000_01b3)
+    })
+}
+
+/// Returns the square with index `s`
+fn square(s: usize) -> Square {
+    Square::from_file_rank(s as u8 % 8, s as u8 / 8).unwrap()
+}
+
+/// Returns the square `steps` squares from square `s` in direction
+/// `(file, rank)`, if it is on the board
+fn step(s: usize, (df, dr): (i8, i8), steps: i8) -> Option<usize> {
+    let file = (s % 8) as i8 + df * steps;
+    let rank = (s / 8) as i8 + dr * steps;
+    ((0..8).contains(&file) && (0..8).contains(&rank)).then(|| (rank * 8 + file) as usize)
+}
+
+/// Returns a bitboard with the squares one step from `s` in each direction
+fn leaper(s: usize, steps: &[(i8, i8)]) -> Bitboard {
+    let bits = steps
+        .iter()
+        .filter_map(|&d| step(s, d, 1))
+        .fold(0, |bits, to| bits | 1 << to);
+    Bitboard(bits)
+}
+
+/// Returns a bitboard with the squares from `s` to the edge of the board in
+/// direction `d`, not counting `s`
+fn ray(s: usize, d: (i8, i8)) -> Bitboard {
+    let bits = (1..)
+        .map_while(|n| step(s, d, n))
+        .fold(0, |bits, to| bits | 1 << to);
+    Bitboard(bits)
+}
+
+#[test]
+fn pawn_attacks() {
+    let colors = [(Color::White, 1), (Color::Black, -1)];
+    let mut table = vec![];
+    for (color, forward) in colors {
+        for s in 0..64 {
+            let attacks = Board::pawn_attacks(square(s), color);
+            assert_eq!(attacks, leaper(s, &[(-1, forward), (1, forward)]), "{s}");
+            table.push(attacks);
+        }
+    }
+    assert_eq!(fingerprint(&table), LEGACY_PAWN_ATTACKS);
+}
+
+#[test]
+fn king_attacks() {
+    let table: Vec<Bitboard> = (0..64).map(|s| Board::king_attacks(square(s))).collect();
+    for (s, &attacks) in table.iter().enumerate() {
+        assert_eq!(attacks, leaper(s, &KING_STEPS), "{s}");
+    }
+    assert_eq!(fingerprint(&table), LEGACY_KING_ATTACKS);
+}
+
+#[test]
+fn knight_attacks() {
+    let table: Vec<Bitboard> = (0..64).map(|s| Board::knight_attacks(square(s))).collect();
+    for (s, &attacks) in table.iter().enumerate() {
+        assert_eq!(attacks, leaper(s, &KNIGHT_STEPS), "{s}");
+    }
+    assert_eq!(fingerprint(&table), LEGACY_KNIGHT_ATTACKS);
+}
+
+#[test]
+fn ray_attacks() {
+    // KING_STEPS are in the order of `Dir`
+    let mut table = vec![];
+    for (&d, &step) in DIRS.iter().zip(&KING_STEPS) {
+        for s in 0..64 {
+            // With nothing in the way, a ray runs to the edge of the board
+            let attacks = magic::ray_attacks(d, square(s), Bitboard(0));
+            assert_eq!(attacks, ray(s, step), "{d:?} {s}");
+            table.push(attacks);
+        }
+    }
+    assert_eq!(fingerprint(&table), LEGACY_RAY_ATTACKS);
+}
+
+#[test]
+fn in_between() {
+    let mut table = vec![];
+    for from in 0..64 {
+        for to in 0..64 {
+            let expected = KING_STEPS
+                .iter()
+                .find_map(|&d| {
+                    let squares: Vec<usize> = (1..).map_while(|n| step(from, d, n)).collect();
+                    let i = squares.iter().position(|&s| s == to)?;
+                    Some(Bitboard(
+                        squares[..i].iter().fold(0, |bits, s| bits | 1 << s),
+                    ))
+                })
+                .unwrap_or(Bitboard(0));
+            let between = Board::in_between(square(from), square(to));
+            assert_eq!(between, expected, "{from} {to}");
+            table.push(between);
+        }
+    }
+    assert_eq!(fingerprint(&table), LEGACY_IN_BETWEEN);
+}
@@ -0,0 +1,108 @@
+use std::time::{Duration, Instant};
+use website::chess::board::Board;
+use website::chess::engine::{search, SearchLimits};
+use website::chess::timeman::{TimeManager, MOVE_OVERHEAD};
+use website::chess::utils::Color;
+
+fn ms(ms: u64) -> Option<Duration> {
+    Some(Duration::from_millis(ms))
+}
+
+#[test]
+fn no_budget_without_time_limits() {
+    let limits = SearchLimits {
+        depth: Some(5),
+        ..Default::default()
+    };
+    assert!(TimeManager::new(&limits, Color::White).is_none());
+    // Only the other side's clock is given
+    let limits = SearchLimits {
+        btime: ms(1000),
+        ..Default::default()
+    };
+    assert!(TimeManager::new(&limits, Color::White).is_none());
+}
+
+#[test]
+fn movetime_is_exact() {
+    let limits = SearchLimits {
+        movetime: ms(500),
+        wtime: ms(60_000),
+        ..Default::default()
+    };
+    let time = TimeManager::new(&limits, Color::White).unwrap();
+    assert_eq!(time.soft(), Duration::from_millis(500));
+    assert_eq!(time.hard(), Duration::from_millis(500));
+}
+
+#[test]
+fn only_movetime_is_used_in_full() {
+    let wait = Duration::from_millis(55);
+    let limits = SearchLimits {
+        movetime: ms(500),
+        ..Default::default()
+    };
+    let time = TimeManager::new(&limits, Color::White).unwrap();
+    std::thread::sleep(wait);
+    assert!(time.should_continue(3, 0));
+
+    // The clock makes both budgets 70ms, but with a stable best move the
+    // soft budget still shrinks below the wait
+    let limits = SearchLimits {
+        wtime: ms(100),
+        movestogo: Some(1),
+        ..Default::default()
+    };
+    let time = TimeManager::new(&limits, Color::White).unwrap();
+    assert_eq!(time.soft(), time.hard());
+    std::thread::sleep(wait);
+    assert!(!time.should_continue(3, 0));
+}
+
+#[test]
+fn budgets_fit_on_the_clock() {
+    for (time, inc, moves_to_go) in [
+        (60_000, 0, None),
+        (60_000, 1000, None),
+        (1000, 0, Some(1)),
+        (100, 50, None),
+        (10, 0, None),
+    ] {
+        let limits = SearchLimits {
+            wtime: ms(60_000),
+            btime: ms(time),
+            binc: ms(inc),
+            movestogo: moves_to_go,
+            ..Default::default()
+        };
+        let budget = TimeManager::new(&limits, Color::Black).unwrap();
+        let time = Duration::from_millis(time);
+        assert!(budget.soft() <= budget.hard());
+        assert!(budget.hard() <= time.saturating_sub(MOVE_OVERHEAD));
+    }
+}
+
+#[test]
+fn more_time_for_fewer_moves() {
+    let budget = |moves_to_go| {
+        let limits = SearchLimits {
+            wtime: ms(60_000),
+            movestogo: Some(moves_to_go),
+            ..Default::default()
+        };
+        TimeManager::new(&limits, Color::White).unwrap().soft()
+    };
+    assert!(budget(5) > budget(40));
+}
+
+#[test]
+fn clock_search_stops_in_time() {
+    let limits = SearchLimits {
+        wtime: ms(2000),
+        ..Default::default()
+    };
+    let start = Instant::now();
+    let result = search(&mut Board::new(), &limits);
+    assert!(result.best_move.is_some());
+    assert!(start.elapsed() < Duration::from_millis(2000));
+}
@@ -0,0 +1,107 @@
+use website::chess::board::Board;
+use website::chess::cmove::{self, CMove};
+use website::chess::engine::{search, search_with_table, SearchLimits};
+use website::chess::tt::{Bound, TranspositionTable};
+use website::chess::utils::Square::*;
+
+#[test]
+fn probe_returns_stored_entry() {
+    let tt = TranspositionTable::new(1);
+    let m = CMove::new(E2, E4, cmove::PAWN_DPUSH);
+    tt.store(42, Some(m), -150, 7, Bound::Lower);
+
+    let entry = tt.probe(42).unwrap();
+    assert_eq!(entry.best_move(), Some(m));
+    assert_eq!(entry.score(), -150);
+    assert_eq!(entry.depth(), 7);
+    assert_eq!(entry.bound(), Bound::Lower);
+    assert!(tt.probe(43).is_none());
+
+    // Storing without a move keeps the one already there
+    tt.store(42, None, 20, 8, Bound::Upper);
+    assert_eq!(tt.probe(42).unwrap().best_move(), Some(m));
+
+    tt.clear();
+    assert!(tt.probe(42).is_none());
+}
+
+#[test]
+fn deep_entries_survive_shallow_ones() {
+    // The smallest table has one bucket, so every key collides
+    let tt = TranspositionTable::new(0);
+    tt.store(1, None, 0, 10, Bound::Exact);
+    tt.store(2, None, 0, 2, Bound::Exact);
+    tt.store(3, None, 0, 3, Bound::Exact);
+    assert_eq!(tt.probe(1).unwrap().depth(), 10);
+    assert!(tt.probe(2).is_none());
+    assert_eq!(tt.probe(3).unwrap().depth(), 3);
+
+    // Until the next search, when they are stale
+    tt.new_search();
+    tt.store(4, None, 0, 1, Bound::Exact);
+    assert_eq!(tt.probe(4).unwrap().depth(), 1);
+    assert_eq!(tt.probe(1).unwrap().depth(), 10);
+    assert!(tt.probe(3).is_none());
+}
+
+#[test]
+fn table_does_not_change_search_result() {
+    let fen = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let limits = SearchLimits {
+        depth: Some(2),
+        ..Default::default()
+    };
+    let tt = TranspositionTable::new(1);
+    let first = search_with_table(
+        &mut Board::from_fen(fen).unwrap(),
+        &limits,
+        &tt,
+        1,
+        &mut |_| {},
+    );
+    let second = search_with_table(
+        &mut Board::from_fen(fen).unwrap(),
+        &limits,
+        &tt,
+        1,
+        &mut |_| {},
+    );
+    let fresh = search(&mut Board::from_fen(fen).unwrap(), &limits);
+    assert_eq!(first.score, fresh.score);
+    assert_eq!(second.score, fresh.score);
+    assert!(second.nodes < first.nodes);
+}
+
+#[test]
+fn threads_share_the_table() {
+    let tt = TranspositionTable::new(0);
+    std::thread::scope(|s| {
+        for id in 0..4u64 {
+            let tt = &tt;
+            s.spawn(move || {
+                for i in 0..10_000u64 {
+                    // Every key has its own score, so a torn entry would show
+                    // up as a key with another key's score
+                    let key = i % 7 + 1;
+                    tt.store(key, None, key as i32, (id + i) as u32 % 20, Bound::Exact);
+                    if let Some(entry) = tt.probe(key) {
+                        assert_eq!(entry.score(), key as i32);
+                    }
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn helper_threads_agree_on_mate() {
+    let limits = SearchLimits {
+        depth: Some(4),
+        ..Default::default()
+    };
+    let tt = TranspositionTable::new(1);
+    let mut board = Board::from_fen("k7/8/2K5/8/8/8/8/7R w - - 0 1").unwrap();
+    let result = search_with_table(&mut board, &limits, &tt, 4, &mut |_| {});
+    assert_eq!(result.score, website::chess::engine::MATE_SCORE - 3);
+    assert!(board.generate_moves().contains(&result.best_move.unwrap()));
+}
@@ -0,0 +1,84 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::Duration;
+
+/// Runs the UCI binary with `input` on stdin and returns what it printed
+fn run_uci(input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_uci"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// Runs the UCI binary on the start position with `go`, sending `stop` half
+/// a second later, and returns what it printed
+fn run_uci_until_stopped(go: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_uci"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    writeln!(stdin, "position startpos\n{go}").unwrap();
+    thread::sleep(Duration::from_millis(500));
+    writeln!(stdin, "stop\nquit").unwrap();
+    let output = child.wait_with_output().unwrap();
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn handshake() {
+    let output = run_uci("uci\nisready\nquit\n");
+    assert!(output.contains("id name"));
+    assert!(output.contains("option name Hash"));
+    assert!(output.contains("option name Threads"));
+    assert!(output.contains("uciok\nreadyok\n"));
+}
+
+#[test]
+fn plays_mate_from_fen_and_moves() {
+    // After 1. Kf1 Kh8 the rook mates on a8
+    let output = run_uci(
+        "position fen 6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1 moves g1f1 g8h8\n\
+         go depth 3\nisready\nquit\n",
+    );
+    assert!(output.contains("score mate 1"), "{output}");
+    assert!(output.contains("bestmove a1a8"), "{output}");
+}
+
+#[test]
+fn reports_null_move_when_mated() {
+    let output = run_uci("position startpos moves f2f3 e7e5 g2g4 d8h4\ngo depth 2\nquit\n");
+    assert!(output.contains("bestmove 0000"), "{output}");
+}
+
+#[test]
+fn infinite_search_waits_for_stop() {
+    let output = run_uci("position startpos\ngo infinite\nstop\nquit\n");
+    assert_eq!(output.matches("bestmove").count(), 1);
+}
+
+#[test]
+fn ponder_search_keeps_its_limits() {
+    let output = run_uci_until_stopped("go ponder wtime 100000 btime 100000 depth 2");
+    assert!(output.contains("info depth 2"), "{output}");
+    assert!(!output.contains("info depth 3"), "{output}");
+    assert_eq!(output.matches("bestmove").count(), 1);
+}
+
+#[test]
+fn searchmoves_are_skipped() {
+    let output = run_uci_until_stopped("go searchmoves e2e4 d2d4 depth 2");
+    assert!(output.contains("info depth 2"), "{output}");
+    assert!(!output.contains("info depth 3"), "{output}");
+}
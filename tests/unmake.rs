@@ -0,0 +1,34 @@
+use website::chess::board::Board;
+
+/// Makes and unmakes every move `depth` plies deep, checking that each
+/// unmake restores the board exactly: pieces, side to move, castling
+/// rights, en passant square, clocks, hash and history
+fn check_unmake(board: &mut Board, depth: u32) {
+    if depth == 0 {
+        return;
+    }
+    for m in board.generate_moves() {
+        let before = board.clone();
+        board.make_move_mut(&m);
+        check_unmake(board, depth - 1);
+        board.unmake_move();
+        assert_eq!(*board, before, "{} after {m}", before.to_fen());
+    }
+}
+
+#[test]
+fn unmake_restores_the_board() {
+    for fen in [
+        // Kiwipete, with castling both ways for both sides
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        // En passant, including the capture that exposes the king
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "8/8/8/KPp4r/8/8/8/6k1 w - c6 0 1",
+        // Promotions, with and without capture
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "1r2k3/P7/8/8/8/8/7p/4K1N1 b - - 12 40",
+    ] {
+        let mut board = Board::from_fen(fen).unwrap();
+        check_unmake(&mut board, 3);
+    }
+}
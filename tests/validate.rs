@@ -0,0 +1,130 @@
+use website::chess::board::{Board, CreateBoardError, PositionError};
+use website::chess::utils::{CPiece, Color, Piece, Square};
+
+fn error(fen: &str) -> PositionError {
+    match Board::from_fen(fen) {
+        Err(CreateBoardError::InvalidPosition(err)) => err,
+        Err(err) => panic!("{fen} gave {err:?}"),
+        Ok(_) => panic!("{fen} was accepted"),
+    }
+}
+
+#[test]
+fn accepts_real_positions() {
+    assert_eq!(Board::new().validate(), Ok(()));
+    for fen in [
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+        "4k3/8/8/8/3pP3/8/8/4K3 b - e3 0 1",
+        "8/8/8/8/8/8/8/K6k w - - 0 1",
+    ] {
+        assert!(Board::from_fen(fen).is_ok(), "{fen}");
+    }
+}
+
+#[test]
+fn kings() {
+    assert_eq!(
+        error("8/8/8/8/8/8/8/K7 w - - 0 1"),
+        PositionError::KingCount {
+            color: Color::Black,
+            count: 0
+        }
+    );
+    assert_eq!(
+        error("kkk5/8/8/8/8/8/8/K7 w - - 0 1"),
+        PositionError::KingCount {
+            color: Color::Black,
+            count: 3
+        }
+    );
+    assert_eq!(
+        error("k7/8/8/8/8/8/8/K6K w - - 0 1"),
+        PositionError::KingCount {
+            color: Color::White,
+            count: 2
+        }
+    );
+}
+
+#[test]
+fn too_many_pieces_and_pawns() {
+    assert_eq!(
+        error("k7/8/8/8/8/P7/PPPPPPPP/K7 w - - 0 1"),
+        PositionError::TooManyPawns {
+            color: Color::White,
+            count: 9
+        }
+    );
+    assert_eq!(
+        error("k7/8/8/8/8/N7/PPPPPPPP/RNBQKBNR w - - 0 1"),
+        PositionError::TooManyPieces {
+            color: Color::White,
+            count: 17
+        }
+    );
+}
+
+#[test]
+fn pawns_on_back_ranks() {
+    assert_eq!(
+        error("k6P/8/8/8/8/8/8/K7 w - - 0 1"),
+        PositionError::PawnOnBackRank(Square::H8)
+    );
+    assert_eq!(
+        error("k7/8/8/8/8/8/8/K2p4 w - - 0 1"),
+        PositionError::PawnOnBackRank(Square::D1)
+    );
+}
+
+#[test]
+fn side_not_to_move_in_check() {
+    let fen = "k6R/8/8/8/8/8/8/K7";
+    assert_eq!(
+        error(&format!("{fen} w - - 0 1")),
+        PositionError::OpponentInCheck
+    );
+    assert!(Board::from_fen(&format!("{fen} b - - 0 1")).is_ok());
+}
+
+#[test]
+fn castling_rights_need_king_and_rook() {
+    assert_eq!(
+        error("r3k3/8/8/8/8/8/8/R3K2R w KQkq - 0 1"),
+        PositionError::CastlingRights('k')
+    );
+    assert_eq!(
+        error("r3k2r/8/8/8/8/8/8/R2K3R w KQkq - 0 1"),
+        PositionError::CastlingRights('K')
+    );
+}
+
+#[test]
+fn en_passant_needs_a_double_pushed_pawn() {
+    assert_eq!(
+        error("4k3/8/8/8/8/8/8/4K3 b - e3 0 1"),
+        PositionError::EnPassant(Square::E4)
+    );
+    // Something is on the square the pawn passed over
+    assert_eq!(
+        error("4k3/8/8/8/4P3/4N3/8/4K3 b - e3 0 1"),
+        PositionError::EnPassant(Square::E4)
+    );
+}
+
+#[test]
+fn piece_lists_are_checked() {
+    let mut pieces = vec![None; 64];
+    pieces[Square::A1 as usize] = Some(CPiece(Piece::King, Color::White));
+    assert!(matches!(
+        Board::from_piece_list(&pieces),
+        Err(CreateBoardError::InvalidPosition(
+            PositionError::KingCount { .. }
+        ))
+    ));
+
+    // The last square counts too
+    pieces[Square::H8 as usize] = Some(CPiece(Piece::King, Color::Black));
+    let board = Board::from_piece_list(&pieces).unwrap();
+    assert_eq!(board.to_piece_list(), pieces);
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::Server;
+use serde_json::{json, Value};
+use std::net::TcpStream;
+use tungstenite::{Message, WebSocket};
+
+type Socket = WebSocket<TcpStream>;
+
+/// Opens a session, resuming session `id` if given
+fn connect(server: &Server, id: Option<&str>) -> Socket {
+    let url = match id {
+        Some(id) => format!("ws://127.0.0.1:{}/ws/chess?session={id}", server.port),
+        None => format!("ws://127.0.0.1:{}/ws/chess", server.port),
+    };
+    tungstenite::client(url, server.connect()).unwrap().0
+}
+
+fn send(socket: &mut Socket, message: Value) {
+    socket.send(Message::text(message.to_string())).unwrap();
+}
+
+fn receive(socket: &mut Socket) -> Value {
+    loop {
+        if let Message::Text(text) = socket.read().unwrap() {
+            return serde_json::from_str(&text).unwrap();
+        }
+    }
+}
+
+#[test]
+fn engine_replies_with_search_info() {
+    let server = Server::start(&[]);
+    let mut socket = connect(&server, None);
+    let session = receive(&mut socket);
+    assert_eq!(session["type"], "session");
+    assert_eq!(session["status"], "ongoing");
+    assert!(session["id"].is_string());
+
+    send(
+        &mut socket,
+        json!({"type": "move", "move": "e2e4", "depth": 3}),
+    );
+    let played = receive(&mut socket);
+    assert_eq!(played["type"], "move");
+    assert_eq!(played["by"], "player");
+    assert_eq!(played["move"]["san"], "e4");
+
+    // An info message for every depth, then the engine's move
+    for depth in 1..=3 {
+        let info = receive(&mut socket);
+        assert_eq!(info["type"], "info");
+        assert_eq!(info["depth"], depth);
+        assert!(!info["pv"].as_array().unwrap().is_empty());
+    }
+    let reply = receive(&mut socket);
+    assert_eq!(reply["type"], "move");
+    assert_eq!(reply["by"], "engine");
+    assert!(reply["fen"].as_str().unwrap().contains(" w "));
+}
+
+#[test]
+fn rejects_bad_messages() {
+    let server = Server::start(&[]);
+    let mut socket = connect(&server, None);
+    receive(&mut socket);
+
+    send(&mut socket, json!({"type": "move", "move": "e2e5"}));
+    assert_eq!(receive(&mut socket)["error"], "illegal move 'e2e5'");
+    send(&mut socket, json!({"type": "dance"}));
+    assert_eq!(receive(&mut socket)["type"], "error");
+
+    let mate = "R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1";
+    send(&mut socket, json!({"type": "new", "fen": mate}));
+    let session = receive(&mut socket);
+    assert_eq!(session["status"], "checkmate");
+    send(&mut socket, json!({"type": "go"}));
+    assert_eq!(receive(&mut socket)["error"], "the game is over");
+    send(&mut socket, json!({"type": "move", "move": "g8h8"}));
+    assert_eq!(receive(&mut socket)["error"], "the game is over");
+}
+
+#[test]
+fn drops_searches_of_a_game_that_changed() {
+    let server = Server::start(&[]);
+    let mut first = connect(&server, None);
+    let id = receive(&mut first)["id"].as_str().unwrap().to_string();
+    let mut second = connect(&server, Some(&id));
+    receive(&mut second);
+
+    // The first socket's engine searches the start position while the second
+    // starts a game its move can't be played in
+    send(&mut first, json!({"type": "go", "movetime": 500}));
+    assert_eq!(receive(&mut first)["type"], "info");
+    let rook = "4k3/8/8/8/8/8/8/R3K3 w - - 0 1";
+    send(&mut second, json!({"type": "new", "fen": rook}));
+    assert_eq!(receive(&mut second)["fen"], rook);
+
+    let reply = loop {
+        let message = receive(&mut first);
+        if message["type"] != "info" {
+            break message;
+        }
+    };
+    assert_eq!(reply["type"], "session");
+    assert_eq!(reply["fen"], rook);
+    assert_eq!(reply["moves"], json!([]));
+
+    send(
+        &mut second,
+        json!({"type": "move", "move": "e1d2", "depth": 1}),
+    );
+    assert_eq!(receive(&mut second)["move"]["san"], "Kd2");
+}
+
+#[test]
+fn sessions_can_be_resumed() {
+    let server = Server::start(&[]);
+    let mut socket = connect(&server, None);
+    let id = receive(&mut socket)["id"].as_str().unwrap().to_string();
+    send(
+        &mut socket,
+        json!({"type": "move", "move": "d4", "depth": 1}),
+    );
+    receive(&mut socket);
+    receive(&mut socket);
+    let reply = receive(&mut socket);
+    assert_eq!(reply["by"], "engine");
+    socket.close(None).unwrap();
+
+    let mut socket = connect(&server, Some(&id));
+    let session = receive(&mut socket);
+    assert_eq!(session["id"], id.as_str());
+    assert_eq!(session["fen"], reply["fen"]);
+    assert_eq!(session["moves"][0], "d2d4");
+    assert_eq!(session["moves"].as_array().unwrap().len(), 2);
+
+    // Unknown sessions start over
+    let mut socket = connect(&server, Some("nonsense"));
+    let session = receive(&mut socket);
+    assert_ne!(session["id"], "nonsense");
+    assert_eq!(session["moves"], json!([]));
+}
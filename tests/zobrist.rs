@@ -0,0 +1,92 @@
+use website::chess::board::Board;
+use website::chess::cmove::CMove;
+use website::chess::utils::{Piece, Square};
+
+/// Walks the move tree `depth` plies deep, checking that the incrementally
+/// updated hash matches the hash of the same position built from scratch
+fn check_hashes(board: &mut Board, depth: u32) {
+    assert_eq!(
+        board.hash(),
+        Board::from_fen(&board.to_fen()).unwrap().hash(),
+        "{}",
+        board.to_fen()
+    );
+    if depth == 0 {
+        return;
+    }
+    for m in board.generate_moves() {
+        let hash = board.hash();
+        board.make_move_mut(&m);
+        assert_ne!(board.hash(), hash);
+        check_hashes(board, depth - 1);
+        board.unmake_move();
+        assert_eq!(board.hash(), hash);
+    }
+}
+
+/// Returns `m` in long algebraic notation, e.g. `e2e4` or `e7e8q`
+fn format_move(m: &CMove) -> String {
+    let square = |s: Square| format!("{}{}", (b'a' + s.file()) as char, s.rank() + 1);
+    let promo = match m.is_promo() {
+        Some(Piece::Knight) => "n",
+        Some(Piece::Bishop) => "b",
+        Some(Piece::Rook) => "r",
+        Some(Piece::Queen) => "q",
+        _ => "",
+    };
+    format!("{}{}{promo}", square(m.get_from()), square(m.get_to()))
+}
+
+#[test]
+fn incremental_hash_matches_scratch_hash() {
+    for (fen, depth) in [
+        (
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            2,
+        ),
+        (
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+            2,
+        ),
+        ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 3),
+    ] {
+        check_hashes(&mut Board::from_fen(fen).unwrap(), depth);
+    }
+}
+
+#[test]
+fn transpositions_have_equal_hashes() {
+    let mut a = Board::new();
+    let mut b = Board::new();
+    let moves = |board: &Board, uci: &str| {
+        board
+            .generate_moves()
+            .into_iter()
+            .find(|m| format_move(m) == uci)
+            .unwrap()
+    };
+    for uci in ["g1f3", "g8f6", "b1c3"] {
+        a.make_move_mut(&moves(&a, uci));
+    }
+    for uci in ["b1c3", "g8f6", "g1f3"] {
+        b.make_move_mut(&moves(&b, uci));
+    }
+    assert_eq!(a.hash(), b.hash());
+    assert_ne!(a.hash(), Board::new().hash());
+}
+
+#[test]
+fn en_passant_file_only_hashed_when_capturable() {
+    // No black pawn can take the e4 pawn, so the position is the same as
+    // without the en passant square
+    let mut board = Board::new();
+    board.make_move_mut(&board.parse_uci_move("e2e4").unwrap());
+    let fen = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1";
+    assert_eq!(board.hash(), Board::from_fen(fen).unwrap().hash());
+
+    // The d4 pawn can take the e4 pawn
+    let fen = "4k3/8/8/8/3pP3/8/8/4K3 b - ";
+    let capturable = Board::from_fen(&format!("{fen}e3 0 1")).unwrap();
+    let not_capturable = Board::from_fen(&format!("{fen}- 0 1")).unwrap();
+    assert_ne!(capturable.hash(), not_capturable.hash());
+}